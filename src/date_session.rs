@@ -0,0 +1,39 @@
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+use crate::{OffsetDateTimeError, helper};
+
+/// A daily session on a specific date, e.g. "2024-03-15 from 09:30 to 16:00"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateSession {
+    pub date: Date,
+    pub open: Time,
+    pub close: Time,
+}
+
+impl DateSession {
+    pub fn new(date: Date, open: Time, close: Time) -> Self {
+        Self { date, open, close }
+    }
+
+    /// Materialize the session as open and close datetimes with timezone offset (hours from UTC)
+    ///
+    /// Overnight sessions (close not after open) close on the next day.
+    ///
+    /// # Returns
+    /// * `Ok((OffsetDateTime, OffsetDateTime))` - Open and close datetimes
+    /// * `Err` - If offset is invalid
+    pub fn to_datetimes(
+        &self,
+        offset_hours: i8,
+    ) -> Result<(OffsetDateTime, OffsetDateTime), OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+
+        let open = PrimitiveDateTime::new(self.date, self.open).assume_offset(offset);
+        let mut close = PrimitiveDateTime::new(self.date, self.close).assume_offset(offset);
+        if close <= open {
+            close += Duration::days(1);
+        }
+
+        Ok((open, close))
+    }
+}
@@ -0,0 +1,42 @@
+use time::{Duration, OffsetDateTime};
+
+/// A half-open range of datetimes, `[start, end)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTimeRange {
+    pub start: OffsetDateTime,
+    pub end: OffsetDateTime,
+}
+
+impl DateTimeRange {
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        Self { start, end }
+    }
+
+    /// Split the range into consecutive windows of `interval`
+    ///
+    /// The last window is cut short at `end` if the range is not a whole number of intervals.
+    ///
+    /// # Arguments
+    /// * `interval` - Window length
+    ///
+    /// # Returns
+    /// Consecutive `(start, end)` pairs covering the range; empty if the range is empty
+    /// or `interval` is not positive
+    pub fn split(&self, interval: Duration) -> Vec<(OffsetDateTime, OffsetDateTime)> {
+        let mut windows = Vec::new();
+        if !interval.is_positive() {
+            return windows;
+        }
+
+        let mut cursor = self.start;
+        while cursor < self.end {
+            let next = cursor
+                .checked_add(interval)
+                .map_or(self.end, |next| next.min(self.end));
+            windows.push((cursor, next));
+            cursor = next;
+        }
+
+        windows
+    }
+}
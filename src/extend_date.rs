@@ -0,0 +1,109 @@
+use thiserror::Error;
+use time::{Date, Duration, Weekday};
+
+use crate::helper;
+
+#[derive(Error, Debug)]
+pub enum DateError {
+    #[error("Date out of range when moving from {0}")]
+    OutOfRange(Date),
+}
+
+/// Extension trait for Date struct providing additional utility methods
+pub trait ExtDate {
+    /// Get the next calendar day
+    ///
+    /// Named apart from `Date::next_day`, which returns `Option` and would take
+    /// precedence in method-call syntax.
+    ///
+    /// # Returns
+    /// * `Ok(Date)` - The following day
+    /// * `Err` - If self is the last representable date
+    fn next_day_checked(&self) -> Result<Date, DateError>;
+
+    /// Get the same day in the next month, clamped to the month's last day
+    ///
+    /// e.g. Jan 31 becomes Feb 28 (or Feb 29 in leap years), Dec 15 becomes Jan 15
+    /// of the next year.
+    ///
+    /// # Returns
+    /// * `Ok(Date)` - Date one month later
+    /// * `Err` - If the result is out of range
+    fn next_month(&self) -> Result<Date, DateError>;
+
+    /// Get the same day in the next year, clamping Feb 29 to Feb 28
+    ///
+    /// # Returns
+    /// * `Ok(Date)` - Date one year later
+    /// * `Err` - If the result is out of range
+    fn next_year(&self) -> Result<Date, DateError>;
+
+    /// Get the start of the week (the most recent `first_day`, or self if it is one)
+    ///
+    /// # Arguments
+    /// * `first_day` - The weekday a week starts on (e.g. Monday, or Sunday for US locales)
+    ///
+    /// # Returns
+    /// * `Ok(Date)` - First day of the week
+    /// * `Err` - If the result is out of range
+    fn start_of_week(&self, first_day: Weekday) -> Result<Date, DateError>;
+
+    /// Get the end of the week (six days after `start_of_week`)
+    ///
+    /// # Arguments
+    /// * `first_day` - The weekday a week starts on
+    ///
+    /// # Returns
+    /// * `Ok(Date)` - Last day of the week
+    /// * `Err` - If the result is out of range
+    fn end_of_week(&self, first_day: Weekday) -> Result<Date, DateError>;
+
+    /// Get the first day of the month
+    fn start_of_month(&self) -> Date;
+
+    /// Get the last day of the month, handling leap years
+    fn end_of_month(&self) -> Date;
+
+    /// Check if the date falls on Saturday or Sunday
+    fn is_weekend(&self) -> bool;
+}
+
+impl ExtDate for Date {
+    fn next_day_checked(&self) -> Result<Date, DateError> {
+        self.next_day().ok_or(DateError::OutOfRange(*self))
+    }
+
+    fn next_month(&self) -> Result<Date, DateError> {
+        helper::add_months_clamped(*self, 1).ok_or(DateError::OutOfRange(*self))
+    }
+
+    fn next_year(&self) -> Result<Date, DateError> {
+        helper::add_months_clamped(*self, 12).ok_or(DateError::OutOfRange(*self))
+    }
+
+    fn start_of_week(&self, first_day: Weekday) -> Result<Date, DateError> {
+        let days_back =
+            (helper::weekday_to_u8(self.weekday()) + 7 - helper::weekday_to_u8(first_day)) % 7;
+        self.checked_sub(Duration::days(days_back as i64))
+            .ok_or(DateError::OutOfRange(*self))
+    }
+
+    fn end_of_week(&self, first_day: Weekday) -> Result<Date, DateError> {
+        self.start_of_week(first_day)?
+            .checked_add(Duration::days(6))
+            .ok_or(DateError::OutOfRange(*self))
+    }
+
+    fn start_of_month(&self) -> Date {
+        self.replace_day(1).expect("Day 1 is valid in every month")
+    }
+
+    fn end_of_month(&self) -> Date {
+        self.replace_day(helper::days_in_month(self.year(), self.month()))
+            .expect("Last day of month is valid")
+    }
+
+    fn is_weekend(&self) -> bool {
+        matches!(self.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+}
@@ -0,0 +1,47 @@
+use time::Duration;
+
+/// Extension trait for Duration struct providing additional utility methods
+pub trait ExtDuration {
+    /// Format duration as a Chinese style string, e.g. "1小时30分"
+    ///
+    /// Days are rolled into hours, zero components are omitted and subseconds are
+    /// truncated. A zero duration formats as "0秒".
+    ///
+    /// # Example
+    /// ```
+    /// use ext_time::ExtDuration;
+    /// use time::Duration;
+    ///
+    /// assert_eq!(Duration::minutes(90).to_chinese_string(), "1小时30分");
+    /// assert_eq!(Duration::hours(26).to_chinese_string(), "26小时");
+    /// ```
+    fn to_chinese_string(&self) -> String;
+}
+
+impl ExtDuration for Duration {
+    fn to_chinese_string(&self) -> String {
+        let total = self.whole_seconds().unsigned_abs();
+        if total == 0 {
+            return "0秒".to_string();
+        }
+
+        let hours = total / 3600;
+        let minutes = (total % 3600) / 60;
+        let seconds = total % 60;
+
+        let mut result = String::new();
+        if self.is_negative() {
+            result.push('-');
+        }
+        if hours > 0 {
+            result.push_str(&format!("{}小时", hours));
+        }
+        if minutes > 0 {
+            result.push_str(&format!("{}分", minutes));
+        }
+        if seconds > 0 {
+            result.push_str(&format!("{}秒", seconds));
+        }
+        result
+    }
+}
@@ -1,58 +1,415 @@
+use std::collections::HashSet;
+use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 use time::{
-    Duration, OffsetDateTime, Time, UtcOffset,
-    format_description::{self},
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+    format_description::{BorrowedFormatItem, OwnedFormatItem, well_known::Rfc3339},
     macros::format_description as fd,
+    parsing::Parsed,
 };
 
+use crate::{AlignMode, ExtTime, format_cache, helper};
+
 #[derive(Error, Debug)]
 pub enum OffsetDateTimeError {
     #[error("Invalid offset hours: {0}")]
     InvalidOffsetHours(i8),
+    #[error("Invalid offset seconds: {0}")]
+    InvalidOffsetSeconds(i32),
+    #[error("Invalid offset: {0}h {1}m {2}s")]
+    InvalidOffsetHms(i8, i8, i8),
+    #[error("Offset of {0} seconds is not a whole number of hours")]
+    OffsetHasMinutes(i32),
     #[error("Invalid timestamp: {0}")]
     InvalidTimestamp(i64),
+    #[error("System time is outside the supported datetime range")]
+    SystemTimeOutOfRange,
     #[error("Invalid milliseconds: {0}")]
     InvalidMilliseconds(u16),
     #[error("Failed to parse datetime: {0}")]
     ParseError(String),
+    #[error("Failed to parse datetime {input:?} at position {position:?}: {message}")]
+    ParseErrorDetailed {
+        input: String,
+        position: Option<usize>,
+        message: String,
+    },
     #[error("Failed to format datetime: {0}")]
     FormatError(String),
+    #[error("Invalid format pattern: {0}")]
+    InvalidFormatPattern(String),
     #[error("Invalid seconds value: {0}")]
     InvalidSeconds(i64),
+    #[error("Invalid milliseconds of day: {0}")]
+    InvalidMillisecondsOfDay(i64),
+    #[error("Invalid duration: {0}")]
+    InvalidDuration(String),
+    #[error("Invalid {0} value: {1}")]
+    InvalidComponent(&'static str, u8),
+    #[error("Year {0} is outside the sort key range 0..=9999")]
+    InvalidSortKeyYear(i32),
+    #[error("Invalid ISO week date: {0}-W{1:02}")]
+    InvalidIsoWeek(i32, u8),
     #[error("Invalid alignment unit: {0}")]
     InvalidAlignmentUnit(u64),
+    #[error("Invalid interval: {0}, must be positive")]
+    InvalidInterval(i64),
     #[error("Failed to add time: {0:?}")]
     AddTimeError(OffsetDateTime),
 }
 
+/// Reason why `try_parse_any` could not parse its input
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseFailure {
+    #[error("Input is empty")]
+    Empty,
+    #[error("Input does not match any supported datetime format")]
+    UnknownFormat,
+    #[error("Input matches a supported format but a component is out of range")]
+    OutOfRange,
+}
+
+/// Check whether the input has the shape of a supported format, ignoring digit values
+fn matches_known_shape(input: &str) -> bool {
+    let shape: String = input
+        .chars()
+        .map(|c| if c.is_ascii_digit() { '0' } else { c })
+        .collect();
+    let is_numeric_offset = |s: &str| matches!(s, "+00:00" | "-00:00");
+
+    if let Some(rest) = shape.strip_prefix("0000-00-00 00:00:00") {
+        return is_numeric_offset(rest);
+    }
+    if let Some(rest) = shape
+        .strip_prefix("0000-00-00")
+        .and_then(|r| r.strip_prefix(['T', 't']))
+        .and_then(|r| r.strip_prefix("00:00:00"))
+    {
+        let offset = match rest.strip_prefix('.') {
+            Some(fraction) => {
+                let offset = fraction.trim_start_matches('0');
+                if offset.len() == fraction.len() {
+                    return false;
+                }
+                offset
+            }
+            None => rest,
+        };
+        return matches!(offset, "Z" | "z") || is_numeric_offset(offset);
+    }
+
+    // Unix timestamp in seconds
+    let digits = shape.strip_prefix('-').unwrap_or(&shape);
+    !digits.is_empty() && digits.bytes().all(|b| b == b'0')
+}
+
+/// Display format shared by `to_display_string` and friends, e.g. "2024-03-15 14:30:45+08:00"
+pub(crate) const DISPLAY_FORMAT: &[BorrowedFormatItem<'_>] = fd!(
+    "[year]-[month]-[day] [hour repr:24]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+);
+
+/// Build a detailed parse error, locating the byte offset where parsing failed
+///
+/// `time` does not report positions, so the format items are replayed one by one
+/// against the input. The position is `None` when every item matched and the
+/// failure came from an out-of-range or inconsistent component.
+pub(crate) fn parse_error_detailed(
+    input: &str,
+    format: &[BorrowedFormatItem<'_>],
+    err: time::error::Parse,
+) -> OffsetDateTimeError {
+    let position = failure_position(input, format.len(), |parsed, remaining, index| {
+        parsed.parse_item(remaining, &format[index]).ok()
+    });
+    detailed_error(input, position, err)
+}
+
+/// Same as `parse_error_detailed`, for a runtime-compiled format
+fn parse_error_detailed_owned(
+    input: &str,
+    format: &OwnedFormatItem,
+    err: time::error::Parse,
+) -> OffsetDateTimeError {
+    let items = match format {
+        OwnedFormatItem::Compound(items) => items,
+        item => std::slice::from_ref(item),
+    };
+    let position = failure_position(input, items.len(), |parsed, remaining, index| {
+        parsed.parse_item(remaining, &items[index]).ok()
+    });
+    detailed_error(input, position, err)
+}
+
+/// Replay `count` format items against `input`, returning the byte offset of the first
+/// item that fails to match, or of trailing characters left after the last item
+fn failure_position<'a>(
+    input: &'a str,
+    count: usize,
+    mut parse_item: impl FnMut(&mut Parsed, &'a [u8], usize) -> Option<&'a [u8]>,
+) -> Option<usize> {
+    let mut parsed = Parsed::new();
+    let mut remaining = input.as_bytes();
+    for index in 0..count {
+        match parse_item(&mut parsed, remaining, index) {
+            Some(rest) => remaining = rest,
+            None => return Some(input.len() - remaining.len()),
+        }
+    }
+    (!remaining.is_empty()).then(|| input.len() - remaining.len())
+}
+
+fn detailed_error(
+    input: &str,
+    position: Option<usize>,
+    err: time::error::Parse,
+) -> OffsetDateTimeError {
+    OffsetDateTimeError::ParseErrorDetailed {
+        input: input.to_string(),
+        position,
+        message: err.to_string(),
+    }
+}
+
+/// Format datetime to Chinese style string in the given offset, optionally with milliseconds
+fn format_chinese(
+    dt: &OffsetDateTime,
+    offset_hours: i8,
+    with_millis: bool,
+) -> Result<String, OffsetDateTimeError> {
+    let format = if with_millis {
+        fd!(
+            "[year]年[month]月[day]日 [hour]时[minute]分[second].[subsecond digits:3]秒 [offset_hour sign:mandatory]:[offset_minute]"
+        )
+    } else {
+        fd!(
+            "[year]年[month]月[day]日 [hour]时[minute]分[second]秒 [offset_hour sign:mandatory]:[offset_minute]"
+        )
+    };
+    dt.to_offset_hours_checked(offset_hours)?
+        .format(format)
+        .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+}
+
+/// Extension trait for OffsetDateTime providing additional utility methods
+///
+/// Methods taking `offset_hours: i8` only support whole-hour offsets and cannot represent
+/// zones such as +05:30 or +05:45. For those, build the offset with
+/// `offset_seconds_to_utcoffset` and call `to_offset` directly; to feed an existing
+/// `UtcOffset` into an `offset_hours` method, convert it with `offset_to_whole_hours`,
+/// which rejects offsets that would otherwise be silently truncated.
 pub trait ExtOffsetDateTime {
     /// Check if two timestamps are in the same minute
     fn is_same_minute(&self, b: &OffsetDateTime) -> bool;
 
+    /// Check if two datetimes fall on the same calendar day
+    ///
+    /// `b` is converted to self's offset first, so the comparison uses the day
+    /// boundaries of the receiver's timezone.
+    fn is_same_day(&self, b: &OffsetDateTime) -> bool;
+
+    /// Check if two datetimes fall in the same hour of the same day, in self's offset
+    fn is_same_hour(&self, b: &OffsetDateTime) -> bool;
+
+    /// Check if two datetimes fall in the same whole second, ignoring subseconds
+    fn is_same_second(&self, b: &OffsetDateTime) -> bool;
+
     /// Reset seconds and subseconds to zero
     fn reset_minute(&self) -> OffsetDateTime;
 
+    /// Reset minutes, seconds and subseconds to zero, keeping the offset
+    fn reset_hour(&self) -> OffsetDateTime;
+
+    /// Reset the time to 00:00:00, keeping the date and offset
+    fn reset_day(&self) -> OffsetDateTime;
+
+    /// Drop subseconds, keeping everything else
+    fn truncate_subseconds(&self) -> OffsetDateTime;
+
+    /// Round up to the next whole second, returning self if subseconds are already zero
+    fn ceil_to_second(&self) -> OffsetDateTime;
+
+    /// Round up to the next whole minute, returning self if already at :00.000
+    fn ceil_to_minute(&self) -> OffsetDateTime;
+
+    /// Round up to the next whole hour, returning self if already at :00:00.000
+    fn ceil_to_hour(&self) -> OffsetDateTime;
+
     /// Get timestamp in milliseconds
     fn milli_timestamp(&self) -> i64;
 
+    /// Get Unix timestamp in seconds rounded (half-up) to a multiple of `interval`
+    ///
+    /// Subseconds are ignored.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(i64)` - Rounded timestamp
+    /// * `Err` - If interval is not positive
+    fn timestamp_rounded(&self, interval: i64) -> Result<i64, OffsetDateTimeError>;
+
+    /// Get timestamp in milliseconds rounded (half-up) to a multiple of `to_millis`
+    ///
+    /// Useful for coarsening event times, e.g. to the nearest 100ms.
+    ///
+    /// # Arguments
+    /// * `to_millis` - Rounding unit in milliseconds; values `<= 0` disable rounding
+    ///
+    /// # Returns
+    /// Rounded timestamp, or the raw `milli_timestamp()` if `to_millis` is not positive
+    fn milli_timestamp_rounded(&self, to_millis: i64) -> i64;
+
+    /// Get the full duration since the Unix epoch (negative before 1970)
+    ///
+    /// Unlike `milli_timestamp`, this keeps nanosecond precision.
+    fn duration_since_epoch(&self) -> Duration;
+
+    /// Convert to the given timezone offset (hours from UTC), keeping the instant
+    ///
+    /// Unlike `to_offset(UtcOffset::from_hms(..).unwrap())`, an invalid offset is
+    /// reported instead of panicking.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Same instant in the new offset
+    /// * `Err` - If offset is invalid
+    fn to_offset_hours_checked(
+        &self,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Get the wall-clock time of this instant in the given timezone offset (hours from UTC)
+    fn time_at_offset(&self, offset_hours: i8) -> Result<Time, OffsetDateTimeError>;
+
     /// Format datetime to display string with timezone
+    ///
+    /// # Panics
+    /// If `offset_hours` is out of range; use `try_to_display_string` for untrusted input.
     fn to_display_string(&self, offset_hours: i8) -> String;
 
+    /// Format datetime to display string with timezone, e.g. "2024-03-15 14:30:45+08:00"
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Formatted datetime
+    /// * `Err` - If offset is invalid or formatting fails
+    fn try_to_display_string(&self, offset_hours: i8) -> Result<String, OffsetDateTimeError>;
+
+    /// Format datetime to display string with a timezone offset given as hours, minutes
+    /// and seconds from UTC, e.g. "2024-03-15 12:00:45+05:30"
+    ///
+    /// The display format has no offset seconds, so `seconds` must be 0.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Formatted datetime
+    /// * `Err` - If the offset components are invalid, `seconds` is non-zero or formatting fails
+    fn to_display_string_hms(
+        &self,
+        hours: i8,
+        minutes: i8,
+        seconds: i8,
+    ) -> Result<String, OffsetDateTimeError>;
+
+    /// Format datetime to display string with timezone, followed by a caller-supplied zone label
+    ///
+    /// e.g. "2024-03-15 14:30:45+08:00 CST". The label is appended as-is; it is not validated
+    /// against the offset.
+    fn to_display_with_label(
+        &self,
+        offset_hours: i8,
+        label: &str,
+    ) -> Result<String, OffsetDateTimeError>;
+
+    /// Format datetime as RFC 3339 in UTC with a `Z` offset (e.g. `2024-03-15T06:30:45Z`)
+    ///
+    /// Subseconds are included only when non-zero.
+    fn to_rfc3339_z(&self) -> Result<String, OffsetDateTimeError>;
+
+    /// Format datetime as `YYYY-MM-DD HH:MM:SS` in the given timezone offset (hours from UTC),
+    /// without the offset suffix
+    fn to_naive_string(&self, offset_hours: i8) -> Result<String, OffsetDateTimeError>;
+
+    /// Format datetime with a runtime `time` format description, e.g. "[year]/[month]/[day]"
+    ///
+    /// Compiled patterns are cached per thread, so repeated calls with the same
+    /// pattern skip re-parsing it. See `clear_format_cache`.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Formatted datetime
+    /// * `Err` - If the pattern is invalid or formatting fails
+    fn format_with(&self, pattern: &str) -> Result<String, OffsetDateTimeError>;
+
+    /// Format many datetimes to display strings with timezone
+    ///
+    /// The offset is validated once and a single compiled format is reused for
+    /// every element, which makes this cheaper than calling `to_display_string`
+    /// in a loop.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` - Formatted strings in input order
+    /// * `Err` - If offset is invalid or formatting fails
+    fn to_display_strings(
+        dts: &[OffsetDateTime],
+        offset_hours: i8,
+    ) -> Result<Vec<String>, OffsetDateTimeError>;
+
+    /// Format datetime as a fixed-width, lexicographically sortable UTC key
+    ///
+    /// Produces `YYYYMMDDHHMMSSfffffffff` (23 chars), so string order equals time order.
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The key
+    /// * `Err(InvalidSortKeyYear)` - If the UTC year is outside 0..=9999, where a sign or
+    ///   fifth digit would break the ordering
+    fn to_sort_key(&self) -> Result<String, OffsetDateTimeError>;
+
+    /// Parse a key produced by `to_sort_key` back into a UTC datetime
+    fn from_sort_key(key: &str) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
     /// Format datetime to Chinese style string with timezone
     fn to_chinese_string(&self) -> String;
 
+    /// Format datetime to Chinese style string with milliseconds and timezone offset (hours from UTC)
+    ///
+    /// e.g. "2024年03月15日 14时30分45.123秒 +08:00"
+    fn to_chinese_string_millis(&self, offset_hours: i8) -> Result<String, OffsetDateTimeError>;
+
     /// Parse timestamp in milliseconds with timezone offset (hours from UTC)
     fn from_milliseconds(
         timestamp: u64,
         offset_hours: i8,
     ) -> Result<OffsetDateTime, OffsetDateTimeError>;
 
+    /// Parse timestamp in milliseconds, returning the UTC datetime and the validated offset separately
+    ///
+    /// Useful when the instant and the zone are stored separately.
+    ///
+    /// # Returns
+    /// * `Ok((OffsetDateTime, UtcOffset))` - Datetime in UTC and the offset for `offset_hours`
+    /// * `Err` - If timestamp or offset is invalid
+    fn from_milliseconds_parts(
+        timestamp: u64,
+        offset_hours: i8,
+    ) -> Result<(OffsetDateTime, UtcOffset), OffsetDateTimeError>;
+
     /// Parse timestamp in seconds with timezone offset (hours from UTC)
     fn from_seconds(
         timestamp: u64,
         offset_hours: i8,
     ) -> Result<OffsetDateTime, OffsetDateTimeError>;
 
+    /// Parse a timestamp that may be in seconds or milliseconds, with timezone offset (hours from UTC)
+    ///
+    /// Values with an absolute value of at least 100_000_000_000 (around year 5138 in seconds,
+    /// 1973 in milliseconds) are treated as milliseconds.
+    ///
+    /// # Returns
+    /// * `Ok((OffsetDateTime, bool))` - Datetime and whether `ts` was read as milliseconds
+    /// * `Err` - If timestamp or offset is invalid
+    fn from_timestamp_auto(
+        ts: i64,
+        offset_hours: i8,
+    ) -> Result<(OffsetDateTime, bool), OffsetDateTimeError>;
+
     /// Parse datetime from date string, time string and milliseconds with timezone
     fn from_date_time(
         date: &str,
@@ -64,14 +421,141 @@ pub trait ExtOffsetDateTime {
     /// Parse datetime from simple format string (YYYYMMDD_HHMM) with timezone
     fn from_simple(dt: &str, offset_hours: i8) -> Result<OffsetDateTime, OffsetDateTimeError>;
 
+    /// Like `from_date_time`, but reports where parsing failed
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Parsed datetime
+    /// * `Err(ParseErrorDetailed)` - With `input` and `position` taken from whichever of
+    ///   `date` or `time` failed, or `input` set to `milli` if it is not below 1000
+    /// * `Err` - If offset is invalid
+    fn from_date_time_detailed(
+        date: &str,
+        time: &str,
+        milli: u64,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Like `from_simple`, but reports where in `dt` parsing failed
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Parsed datetime
+    /// * `Err(ParseErrorDetailed)` - If `dt` is not in YYYYMMDD_HHMM format
+    /// * `Err` - If offset is invalid
+    fn from_simple_detailed(
+        dt: &str,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Parse datetime with a runtime `time` format description
+    ///
+    /// The pattern must include the date, time and offset components. Compiled
+    /// patterns share the per-thread cache used by `format_with`.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Parsed datetime
+    /// * `Err` - If the pattern is invalid or the input does not match it
+    fn from_pattern(s: &str, pattern: &str) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Like `from_pattern`, but reports where in `s` parsing failed
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Parsed datetime
+    /// * `Err(ParseErrorDetailed)` - If the input does not match the pattern
+    /// * `Err` - If the pattern is invalid
+    fn from_pattern_detailed(s: &str, pattern: &str)
+    -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Parse datetime from any supported format, reporting a typed failure reason
+    ///
+    /// Supported formats:
+    /// * RFC 3339, e.g. `2024-03-15T14:30:45.250+08:00` or `2024-03-15T06:30:45Z`
+    /// * Display format, e.g. `2024-03-15 14:30:45+08:00` (as produced by `to_display_string`)
+    /// * Unix timestamp in seconds, e.g. `1710484245` (returned in UTC)
+    ///
+    /// Surrounding whitespace is ignored.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Parsed datetime
+    /// * `Err(ParseFailure::Empty)` - If the input is blank
+    /// * `Err(ParseFailure::UnknownFormat)` - If the input has none of the supported shapes
+    /// * `Err(ParseFailure::OutOfRange)` - If the shape matches but a value is invalid (e.g. month 13)
+    fn try_parse_any(input: &str) -> Result<OffsetDateTime, ParseFailure>;
+
     /// Convert date format from YYYYMMDD to YYYY.MM.DD
     fn convert_to_dot_date(input: &str) -> Result<String, OffsetDateTimeError>;
 
+    /// Like `convert_to_dot_date`, but reports where in `input` parsing failed
+    ///
+    /// # Returns
+    /// * `Ok(String)` - Date in YYYY.MM.DD format
+    /// * `Err(ParseErrorDetailed)` - If `input` is not a valid YYYYMMDD date
+    fn convert_to_dot_date_detailed(input: &str) -> Result<String, OffsetDateTimeError>;
+
     /// Get current time with specified timezone offset (hours from UTC)
+    ///
+    /// # Panics
+    /// If `offset_hours` is out of range; use `try_now_with_offset` for untrusted input.
     fn now_with_offset(offset_hours: i8) -> OffsetDateTime {
-        OffsetDateTime::now_utc().to_offset(UtcOffset::from_hms(offset_hours, 0, 0).unwrap())
+        Self::try_now_with_offset(offset_hours).expect("Invalid offset hours")
+    }
+
+    /// Get current time with specified timezone offset (hours from UTC)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Current time in the given offset
+    /// * `Err` - If offset is invalid
+    fn try_now_with_offset(offset_hours: i8) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        Ok(OffsetDateTime::now_utc().to_offset(offset))
+    }
+
+    /// Get current time with a timezone offset given as hours, minutes and seconds from UTC
+    ///
+    /// Supports sub-hour offsets such as India (5, 30, 0) or Nepal (5, 45, 0). Negative
+    /// offsets use negative components, e.g. (-3, -30, 0) for Newfoundland.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Current time in the given offset
+    /// * `Err` - If the offset components are invalid
+    fn now_with_offset_hms(
+        hours: i8,
+        minutes: i8,
+        seconds: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hms(hours, minutes, seconds)?;
+        Ok(OffsetDateTime::now_utc().to_offset(offset))
     }
 
+    /// Get today's date in the given timezone offset (hours from UTC) at the given seconds of day
+    ///
+    /// # Arguments
+    /// * `seconds` - Total seconds (hours * 3600 + minutes * 60 + seconds)
+    /// * `offset_hours` - Timezone offset in hours from UTC
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Today at the given time
+    /// * `Err` - If seconds or offset is invalid
+    fn today_at_seconds(
+        seconds: i64,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Build a datetime from a date and seconds of day in the given timezone offset (hours from UTC)
+    ///
+    /// # Arguments
+    /// * `date` - Calendar date in the target offset
+    /// * `seconds` - Total seconds (hours * 3600 + minutes * 60 + seconds), in 0..86400
+    /// * `offset_hours` - Timezone offset in hours from UTC
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Datetime at the given date and time
+    /// * `Err` - If seconds or offset is invalid
+    fn from_date_and_seconds(
+        date: Date,
+        seconds: i64,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
     /// Replace time part with seconds (hours + minutes + seconds)
     ///
     /// # Arguments
@@ -85,16 +569,164 @@ pub trait ExtOffsetDateTime {
         seconds: i64,
     ) -> Result<OffsetDateTime, OffsetDateTimeError>;
 
-    /// Align time to the nearest interval
+    /// Replace time part with a parsed time string, keeping the date and offset
+    ///
+    /// # Arguments
+    /// * `s` - Time string in "HH:MM" or "HH:MM:SS[.fff]" format
+    /// * `allow_24` - Accept "24:00" (or "24:00:00") as end of day, i.e. 00:00 of the next day
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - DateTime with new time part
+    /// * `Err` - If the string is not a valid time
+    fn set_time_str(&self, s: &str, allow_24: bool) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Replace time part with milliseconds of day
     ///
     /// # Arguments
-    /// * `interval` - Interval in seconds (can be negative for backward alignment)
+    /// * `millis` - Total milliseconds since midnight (0..86_400_000)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - DateTime with new time part
+    /// * `Err` - If milliseconds value is invalid
+    fn replace_time_with_millis(&self, millis: i64) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Replace the hour, keeping minute, second, subsecond, date and offset
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - DateTime with new hour
+    /// * `Err` - If hour is not in 0..24
+    fn with_hour(&self, hour: u8) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Replace the minute, keeping hour, second, subsecond, date and offset
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - DateTime with new minute
+    /// * `Err` - If minute is not in 0..60
+    fn with_minute(&self, minute: u8) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Replace the second, keeping hour, minute, subsecond, date and offset
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - DateTime with new second
+    /// * `Err` - If second is not in 0..60
+    fn with_second(&self, second: u8) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Align time down to the interval
+    ///
+    /// Delegates to `align_to_floor`, kept for backward compatibility. A negative
+    /// interval is treated as its absolute value, and 0 is reported as
+    /// `InvalidAlignmentUnit` rather than `InvalidInterval`.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (sign is ignored)
     ///
     /// # Returns
     /// * `Ok(OffsetDateTime)` - Aligned time
     /// * `Err(Error)` - If interval is 0
     fn align_to(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError>;
 
+    /// Align time down to the previous interval boundary (or keep it if already aligned)
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Aligned datetime
+    /// * `Err` - If interval is not positive
+    fn align_to_floor(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        self.align_to_mode(interval, AlignMode::Floor)
+    }
+
+    /// Align time up to the next interval boundary, keeping datetimes already on a boundary
+    ///
+    /// Rounding up past the end of the day advances to the next day's 00:00.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Aligned datetime
+    /// * `Err` - If interval is not positive
+    fn align_to_ceil(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        self.align_to_mode(interval, AlignMode::Ceil)
+    }
+
+    /// Align time to the nearest interval boundary, ties rounding up
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Aligned datetime
+    /// * `Err` - If interval is not positive
+    fn align_to_nearest(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        self.align_to_mode(interval, AlignMode::Round)
+    }
+
+    /// Align time to the interval grid using the given mode
+    ///
+    /// Boundaries are multiples of `interval` counted from midnight in the datetime's
+    /// own offset, like `align_to`. Rounding up past the end of the day advances the
+    /// date to the next day's 00:00. Subseconds are taken into account.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    /// * `mode` - Floor, ceil or round (half-up)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Aligned datetime
+    /// * `Err` - If interval is not positive
+    fn align_to_mode(
+        &self,
+        interval: i64,
+        mode: AlignMode,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Snap to the nearest interval boundary, counted from the Unix epoch
+    ///
+    /// Ties go to the earlier boundary. The result keeps self's offset.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - The nearest boundary
+    /// * `Err` - If interval is not positive or the result is out of range
+    fn snap_to(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Round down to a multiple of `unit`, counted from the Unix epoch
+    ///
+    /// Works for any granularity, e.g. 250ms, 15 minutes or a day. Being epoch-based,
+    /// day boundaries fall on UTC midnight. The result keeps self's offset.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - The boundary at or before self
+    /// * `Err` - If unit is not positive or the result is out of range
+    fn floor_to(&self, unit: Duration) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Round up to a multiple of `unit`, counted from the Unix epoch
+    ///
+    /// Datetimes already on a boundary are returned unchanged. The result keeps self's offset.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - The boundary at or after self
+    /// * `Err` - If unit is not positive or the result is out of range
+    fn ceil_to(&self, unit: Duration) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Get the largest interval boundary strictly before self
+    ///
+    /// Boundaries are multiples of `interval` counted from midnight in the datetime's
+    /// own offset. If self is on a boundary this steps back one full interval; at
+    /// 00:00 it moves to the last boundary of the previous date.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - The previous boundary
+    /// * `Err` - If interval is not positive
+    fn prev_aligned(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
     /// Get next day at the same time
     fn next_day(&self) -> OffsetDateTime;
 
@@ -107,6 +739,28 @@ pub trait ExtOffsetDateTime {
     /// Get next second
     fn next_second(&self) -> OffsetDateTime;
 
+    /// Get previous day at the same time
+    fn prev_day(&self) -> OffsetDateTime;
+
+    /// Get previous hour at the same minute and second
+    fn prev_hour(&self) -> OffsetDateTime;
+
+    /// Get previous minute at the same second
+    fn prev_minute(&self) -> OffsetDateTime;
+
+    /// Get previous second
+    fn prev_second(&self) -> OffsetDateTime;
+
+    /// Get the `n`th tick of a schedule starting at self, i.e. self + `interval * n` seconds
+    ///
+    /// `n` may be negative to step backwards. Unlike `ExtTime::nth_tick`, a datetime
+    /// cannot wrap around, so this returns `Result` and reports overflow instead of panicking.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - The tick
+    /// * `Err(AddTimeError)` - If `interval * n` overflows or the result is out of range
+    fn nth_tick(&self, interval: i64, n: i64) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
     /// Convert time part to seconds, ignoring minutes and seconds
     ///
     /// # Returns
@@ -142,43 +796,437 @@ pub trait ExtOffsetDateTime {
     /// let duration = now.duration_to_time(20, 0, 0); // Duration to 20:00:00
     /// ```
     fn duration_to_time(&self, target_hour: u8, target_minute: u8, target_second: u8) -> Duration;
+
+    /// Calculate duration to the next occurrence of `target` in the datetime's own offset
+    ///
+    /// Same as `duration_to_time`, but takes a `Time`. Returns zero when self is
+    /// already at `target`.
+    ///
+    /// # Example
+    /// ```
+    /// use ext_time::ExtOffsetDateTime;
+    /// use time::{Duration, macros::{datetime, time}};
+    ///
+    /// let dt = datetime!(2024-03-15 23:59:59 +08:00);
+    /// assert_eq!(dt.duration_to(time!(0:00)), Duration::seconds(1));
+    /// ```
+    fn duration_to(&self, target: Time) -> Duration;
+
+    /// Format the cross-day-aware duration until the next occurrence of `target` as HH:MM:SS
+    ///
+    /// Subseconds are truncated.
+    ///
+    /// # Example
+    /// ```
+    /// use ext_time::ExtOffsetDateTime;
+    /// use time::macros::{datetime, time};
+    ///
+    /// let dt = datetime!(2024-03-15 08:15:30 +08:00);
+    /// assert_eq!(dt.countdown_to(time!(9:30)), "01:14:30");
+    /// ```
+    fn countdown_to(&self, target: Time) -> String;
+
+    /// Calculate duration until the next 00:00:00 in the datetime's own offset
+    ///
+    /// Unlike `duration_to_time(0, 0, 0)`, which returns zero when already at midnight,
+    /// this always returns the time until the *next* midnight, i.e. a full 24 hours
+    /// when self is exactly at 00:00:00.
+    fn until_next_day(&self) -> Duration;
+
+    /// Number of seconds in a day
+    ///
+    /// Always 86400, since fixed offsets have no DST transitions.
+    fn seconds_in_day() -> i64;
+
+    /// Whole seconds until the next 00:00:00 in the datetime's own offset
+    ///
+    /// Returns 86400 when self is exactly at midnight; subseconds are truncated.
+    fn seconds_remaining_today(&self) -> i64;
+
+    /// Get the fraction of the day starting at self that lies inside a window
+    ///
+    /// Computes how much of `[self, self.next_day())` overlaps `[window_start, window_end)`.
+    ///
+    /// # Returns
+    /// A value from 0.0 (no overlap) to 1.0 (fully covered)
+    fn window_coverage(&self, window_start: OffsetDateTime, window_end: OffsetDateTime) -> f64;
+
+    /// Check if self is within the duration before `now`, i.e. in [now - d, now]
+    fn within_last(&self, d: Duration, now: OffsetDateTime) -> bool;
+
+    /// Check if self is within the duration after `now`, i.e. in [now, now + d]
+    fn within_next(&self, d: Duration, now: OffsetDateTime) -> bool;
+
+    /// Clamp datetime into the range [min, max]
+    ///
+    /// Comparison is done by instant, so the arguments may carry different offsets.
+    ///
+    /// # Returns
+    /// `min` if self is earlier than `min`, `max` if self is later than `max`, otherwise self
+    fn clamp_dt(&self, min: OffsetDateTime, max: OffsetDateTime) -> OffsetDateTime;
+
+    /// Check if the date is the first day of its month (in the datetime's own offset)
+    fn is_first_of_month(&self) -> bool;
+
+    /// Check if the date is the last day of its month (in the datetime's own offset), handling leap years
+    fn is_last_of_month(&self) -> bool;
+
+    /// Get the start of the week (00:00:00 on the most recent `first_day`), preserving offset
+    ///
+    /// # Arguments
+    /// * `first_day` - The weekday a week starts on (e.g. Monday, or Sunday for US locales)
+    fn start_of_week(&self, first_day: Weekday) -> OffsetDateTime;
+
+    /// Get the start of a Monday-first week, preserving offset
+    fn start_of_week_monday(&self) -> OffsetDateTime {
+        self.start_of_week(Weekday::Monday)
+    }
+
+    /// Check if two datetimes fall in the same week
+    ///
+    /// `other` is converted to self's offset first. Weeks spanning a year boundary are
+    /// treated as one week; with `Weekday::Monday` this matches ISO weeks.
+    ///
+    /// # Arguments
+    /// * `first_day` - The weekday a week starts on
+    fn same_week(&self, other: &OffsetDateTime, first_day: Weekday) -> bool;
+
+    /// Get the start of the quarter (first day of Jan/Apr/Jul/Oct at 00:00:00), preserving offset
+    fn start_of_quarter(&self) -> OffsetDateTime;
+
+    /// Get the end of the quarter (last day of Mar/Jun/Sep/Dec at 23:59:59.999999999), preserving offset
+    fn end_of_quarter(&self) -> OffsetDateTime;
+
+    /// Convert to `std::time::SystemTime`, supporting instants before the Unix epoch
+    ///
+    /// # Returns
+    /// * `Ok(SystemTime)` - The same instant
+    /// * `Err(SystemTimeOutOfRange)` - If the platform's `SystemTime` cannot represent it,
+    ///   e.g. dates before 1601 on Windows
+    fn to_system_time(&self) -> Result<SystemTime, OffsetDateTimeError>;
+
+    /// Convert from `std::time::SystemTime` with timezone offset (hours from UTC)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Converted datetime in the given offset
+    /// * `Err` - If offset is invalid or the time is out of range
+    fn from_system_time(
+        t: SystemTime,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Count calendar days from self to `other`, ignoring the time of day
+    ///
+    /// Each datetime's date is taken in its own offset, so 23:59 Monday to 00:01
+    /// Tuesday is 1 day. Convert both to a common offset first if the instants
+    /// should be compared in the same timezone.
+    ///
+    /// # Returns
+    /// Number of days, negative if `other` is on an earlier date
+    fn calendar_days_between(&self, other: &OffsetDateTime) -> i64;
+
+    /// Break the elapsed time from self to `other` into days, hours, minutes and seconds
+    ///
+    /// Days are floored, so the hours/minutes/seconds are always the non-negative
+    /// remainder: -1 hour is `(-1, 23, 0, 0)`. Subseconds are truncated.
+    ///
+    /// # Returns
+    /// `(days, hours, minutes, seconds)` of `other - self`
+    fn diff_components(&self, other: &OffsetDateTime) -> (i64, u8, u8, u8);
+
+    /// Calculate the calendar age since `past` as (years, months, days)
+    ///
+    /// `past` is converted to self's offset first. Months are counted as calendar
+    /// months, clamping to the end of shorter months (e.g. Jan 31 + 1 month = Feb 28/29),
+    /// and the remaining whole days are counted from there, so an incomplete last day
+    /// is not included.
+    ///
+    /// # Returns
+    /// * `Ok((years, months, days))` - The age, or `(0, 0, 0)` if `past` is later than self
+    /// * `Err` - If `past` cannot be represented in self's offset
+    fn age_since(&self, past: OffsetDateTime) -> Result<(i64, u8, u8), OffsetDateTimeError>;
+
+    /// Build datetime from ISO year, week and weekday with time and timezone offset (hours from UTC)
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Datetime at `time` on the given ISO week date
+    /// * `Err` - If the week date or offset is invalid
+    fn from_iso_week(
+        year: i32,
+        week: u8,
+        weekday: Weekday,
+        time: Time,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Format the date (in the datetime's own offset) as an ISO week date, e.g. "2024-W11-5"
+    ///
+    /// The weekday is numbered from Monday (1) to Sunday (7).
+    fn to_iso_week_string(&self) -> String;
+
+    /// Parse an ISO week date such as "2024-W11-5" to 00:00:00 on that day in the given
+    /// timezone offset (hours from UTC)
+    ///
+    /// Inverse of `to_iso_week_string`.
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Start of the given ISO week date
+    /// * `Err` - If the string is malformed, the week does not exist or offset is invalid
+    fn from_iso_week_string(
+        s: &str,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
+    /// Check if the date is a business day (Monday to Friday and not a holiday)
+    fn is_business_day(&self, holidays: &HashSet<Date>) -> bool;
+
+    /// Iterate business days from `start` to `end` (inclusive) at `start`'s time of day
+    ///
+    /// Weekends and dates in `holidays` are skipped. Dates are taken in `start`'s offset.
+    fn business_days_between(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        holidays: &HashSet<Date>,
+    ) -> impl Iterator<Item = OffsetDateTime>;
+
+    /// Count seconds between two datetimes that fall within daily business hours
+    ///
+    /// Only the parts of `[start, end)` inside the daily `[open, close)` session on
+    /// business days are counted. Dates are taken in `start`'s offset. If `close` is not
+    /// after `open`, the session runs overnight and belongs to the day it opens on.
+    ///
+    /// # Returns
+    /// In-session seconds, or 0 if `end` is not after `start`
+    fn business_seconds_between(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        open: Time,
+        close: Time,
+        holidays: &HashSet<Date>,
+    ) -> i64;
 }
 
-impl ExtOffsetDateTime for OffsetDateTime {
-    fn is_same_minute(&self, b: &OffsetDateTime) -> bool {
-        self.hour() == b.hour() && self.minute() == b.minute()
+impl ExtOffsetDateTime for OffsetDateTime {
+    fn is_same_minute(&self, b: &OffsetDateTime) -> bool {
+        self.hour() == b.hour() && self.minute() == b.minute()
+    }
+
+    fn is_same_day(&self, b: &OffsetDateTime) -> bool {
+        self.date() == b.to_offset(self.offset()).date()
+    }
+
+    fn is_same_hour(&self, b: &OffsetDateTime) -> bool {
+        let b = b.to_offset(self.offset());
+        self.date() == b.date() && self.hour() == b.hour()
+    }
+
+    fn is_same_second(&self, b: &OffsetDateTime) -> bool {
+        self.unix_timestamp() == b.unix_timestamp()
+    }
+
+    fn reset_minute(&self) -> OffsetDateTime {
+        let time = Time::from_hms(self.hour(), self.minute(), 0).expect("Invalid time components");
+        self.replace_time(time)
+    }
+
+    fn reset_hour(&self) -> OffsetDateTime {
+        let time = Time::from_hms(self.hour(), 0, 0).expect("Invalid time components");
+        self.replace_time(time)
+    }
+
+    fn reset_day(&self) -> OffsetDateTime {
+        self.replace_time(Time::MIDNIGHT)
+    }
+
+    fn truncate_subseconds(&self) -> OffsetDateTime {
+        self.replace_nanosecond(0).expect("Invalid nanosecond")
+    }
+
+    fn ceil_to_second(&self) -> OffsetDateTime {
+        let floor = self.truncate_subseconds();
+        if floor == *self {
+            floor
+        } else {
+            floor + Duration::seconds(1)
+        }
+    }
+
+    fn ceil_to_minute(&self) -> OffsetDateTime {
+        let floor = self.reset_minute();
+        if floor == *self {
+            floor
+        } else {
+            floor + Duration::minutes(1)
+        }
+    }
+
+    fn ceil_to_hour(&self) -> OffsetDateTime {
+        let time = Time::from_hms(self.hour(), 0, 0).expect("Invalid time components");
+        let floor = self.replace_time(time);
+        if floor == *self {
+            floor
+        } else {
+            floor + Duration::hours(1)
+        }
+    }
+
+    fn milli_timestamp(&self) -> i64 {
+        self.unix_timestamp() * 1000 + self.millisecond() as i64
+    }
+
+    fn timestamp_rounded(&self, interval: i64) -> Result<i64, OffsetDateTimeError> {
+        if interval <= 0 {
+            return Err(OffsetDateTimeError::InvalidInterval(interval));
+        }
+
+        let ts = self.unix_timestamp() as i128;
+        let interval = interval as i128;
+        let floor = ts.div_euclid(interval) * interval;
+        let rounded = if (ts - floor) * 2 >= interval {
+            floor + interval
+        } else {
+            floor
+        };
+
+        i64::try_from(rounded).map_err(|_| OffsetDateTimeError::InvalidTimestamp(ts as i64))
+    }
+
+    fn milli_timestamp_rounded(&self, to_millis: i64) -> i64 {
+        let ts = self.milli_timestamp();
+        if to_millis <= 0 {
+            return ts;
+        }
+
+        let ts = ts as i128;
+        let unit = to_millis as i128;
+        let floor = ts.div_euclid(unit) * unit;
+        let rounded = if (ts - floor) * 2 >= unit {
+            floor + unit
+        } else {
+            floor
+        };
+
+        // The result is within half a unit of a valid timestamp, so it fits in i64
+        rounded as i64
+    }
+
+    fn duration_since_epoch(&self) -> Duration {
+        Duration::seconds(self.unix_timestamp()) + Duration::nanoseconds(self.nanosecond() as i64)
     }
 
-    fn reset_minute(&self) -> OffsetDateTime {
-        let time = Time::from_hms(self.hour(), self.minute(), 0).expect("Invalid time components");
-        self.replace_time(time)
+    fn to_offset_hours_checked(
+        &self,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        Ok(self.to_offset(offset))
     }
 
-    fn milli_timestamp(&self) -> i64 {
-        (self.unix_timestamp() as i64) * 1000 + self.millisecond() as i64
+    fn time_at_offset(&self, offset_hours: i8) -> Result<Time, OffsetDateTimeError> {
+        Ok(self.to_offset_hours_checked(offset_hours)?.time())
     }
 
     fn to_display_string(&self, offset_hours: i8) -> String {
-        let offset = UtcOffset::from_hms(offset_hours, 0, 0).expect("Invalid offset hours");
-        self.to_offset(offset)
-            .format(
-                &format_description::parse(
-                    "[year]-[month]-[day] [hour repr:24]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
-                )
-                .unwrap(),
-            )
+        self.try_to_display_string(offset_hours)
             .expect("Failed to format datetime")
     }
 
-    fn to_chinese_string(&self) -> String {
-        let offset = UtcOffset::from_hms(8, 0, 0).expect("Invalid offset hours");
-        let format = format_description::parse(
-            "[year]年[month]月[day]日 [hour]时[minute]分[second]秒 [offset_hour sign:mandatory]:[offset_minute]",
-        )
-        .expect("parse");
+    fn try_to_display_string(&self, offset_hours: i8) -> Result<String, OffsetDateTimeError> {
+        self.to_offset_hours_checked(offset_hours)?
+            .format(DISPLAY_FORMAT)
+            .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+    }
+
+    fn to_display_string_hms(
+        &self,
+        hours: i8,
+        minutes: i8,
+        seconds: i8,
+    ) -> Result<String, OffsetDateTimeError> {
+        if seconds != 0 {
+            return Err(OffsetDateTimeError::InvalidOffsetHms(
+                hours, minutes, seconds,
+            ));
+        }
+        let offset = helper::offset_from_hms(hours, minutes, seconds)?;
         self.to_offset(offset)
-            .format(&format)
-            .expect("Failed to format datetime")
+            .format(DISPLAY_FORMAT)
+            .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+    }
+
+    fn to_display_with_label(
+        &self,
+        offset_hours: i8,
+        label: &str,
+    ) -> Result<String, OffsetDateTimeError> {
+        let formatted = self.try_to_display_string(offset_hours)?;
+        Ok(format!("{} {}", formatted, label))
+    }
+
+    fn to_naive_string(&self, offset_hours: i8) -> Result<String, OffsetDateTimeError> {
+        self.to_offset_hours_checked(offset_hours)?
+            .format(fd!("[year]-[month]-[day] [hour repr:24]:[minute]:[second]"))
+            .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+    }
+
+    fn format_with(&self, pattern: &str) -> Result<String, OffsetDateTimeError> {
+        format_cache::with_format(pattern, |format| self.format(format))?
+            .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+    }
+
+    fn to_display_strings(
+        dts: &[OffsetDateTime],
+        offset_hours: i8,
+    ) -> Result<Vec<String>, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        dts.iter()
+            .map(|dt| {
+                dt.to_offset(offset)
+                    .format(DISPLAY_FORMAT)
+                    .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+            })
+            .collect()
+    }
+
+    fn to_sort_key(&self) -> Result<String, OffsetDateTimeError> {
+        let utc = self
+            .checked_to_offset(UtcOffset::UTC)
+            .ok_or(OffsetDateTimeError::InvalidSortKeyYear(self.year()))?;
+        if !(0..=9999).contains(&utc.year()) {
+            return Err(OffsetDateTimeError::InvalidSortKeyYear(utc.year()));
+        }
+
+        Ok(format!(
+            "{:04}{:02}{:02}{:02}{:02}{:02}{:09}",
+            utc.year(),
+            utc.month() as u8,
+            utc.day(),
+            utc.hour(),
+            utc.minute(),
+            utc.second(),
+            utc.nanosecond()
+        ))
+    }
+
+    fn from_sort_key(key: &str) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let format = fd!("[year][month][day][hour][minute][second][subsecond digits:9]");
+        PrimitiveDateTime::parse(key, &format)
+            .map(PrimitiveDateTime::assume_utc)
+            .map_err(|e| parse_error_detailed(key, format, e))
+    }
+
+    fn to_rfc3339_z(&self) -> Result<String, OffsetDateTimeError> {
+        self.to_offset(UtcOffset::UTC)
+            .format(&Rfc3339)
+            .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+    }
+
+    fn to_chinese_string(&self) -> String {
+        format_chinese(self, 8, false).expect("Failed to format datetime")
+    }
+
+    fn to_chinese_string_millis(&self, offset_hours: i8) -> Result<String, OffsetDateTimeError> {
+        format_chinese(self, offset_hours, true)
     }
 
     fn from_milliseconds(
@@ -187,8 +1235,7 @@ impl ExtOffsetDateTime for OffsetDateTime {
     ) -> Result<OffsetDateTime, OffsetDateTimeError> {
         let seconds = timestamp / 1000;
         let millis = timestamp % 1000;
-        let offset = UtcOffset::from_hms(offset_hours, 0, 0)
-            .map_err(|_| OffsetDateTimeError::InvalidOffsetHours(offset_hours))?;
+        let offset = helper::offset_from_hours(offset_hours)?;
 
         let dt = OffsetDateTime::from_unix_timestamp(seconds as i64)
             .map_err(|_| OffsetDateTimeError::InvalidTimestamp(seconds as i64))?;
@@ -200,12 +1247,20 @@ impl ExtOffsetDateTime for OffsetDateTime {
         Ok(dt.to_offset(offset))
     }
 
+    fn from_milliseconds_parts(
+        timestamp: u64,
+        offset_hours: i8,
+    ) -> Result<(OffsetDateTime, UtcOffset), OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        let dt = Self::from_milliseconds(timestamp, 0)?;
+        Ok((dt, offset))
+    }
+
     fn from_seconds(
         timestamp: u64,
         offset_hours: i8,
     ) -> Result<OffsetDateTime, OffsetDateTimeError> {
-        let offset = UtcOffset::from_hms(offset_hours, 0, 0)
-            .map_err(|_| OffsetDateTimeError::InvalidOffsetHours(offset_hours))?;
+        let offset = helper::offset_from_hours(offset_hours)?;
 
         let dt = OffsetDateTime::from_unix_timestamp(timestamp as i64)
             .map_err(|_| OffsetDateTimeError::InvalidTimestamp(timestamp as i64))?;
@@ -213,6 +1268,23 @@ impl ExtOffsetDateTime for OffsetDateTime {
         Ok(dt.to_offset(offset))
     }
 
+    fn from_timestamp_auto(
+        ts: i64,
+        offset_hours: i8,
+    ) -> Result<(OffsetDateTime, bool), OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+
+        let is_millis = ts.unsigned_abs() >= 100_000_000_000;
+        let dt = if is_millis {
+            OffsetDateTime::from_unix_timestamp_nanos(ts as i128 * 1_000_000)
+        } else {
+            OffsetDateTime::from_unix_timestamp(ts)
+        }
+        .map_err(|_| OffsetDateTimeError::InvalidTimestamp(ts))?;
+
+        Ok((dt.to_offset(offset), is_millis))
+    }
+
     fn from_date_time(
         date_str: &str,
         time_str: &str,
@@ -238,6 +1310,82 @@ impl ExtOffsetDateTime for OffsetDateTime {
             .map_err(|e| OffsetDateTimeError::ParseError(e.to_string()))
     }
 
+    fn from_date_time_detailed(
+        date_str: &str,
+        time_str: &str,
+        milli: u64,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+
+        let date_format = fd!("[year][month][day]");
+        let date = Date::parse(date_str, date_format)
+            .map_err(|e| parse_error_detailed(date_str, date_format, e))?;
+        let time_format = fd!("[hour]:[minute]:[second]");
+        let time = Time::parse(time_str, time_format)
+            .map_err(|e| parse_error_detailed(time_str, time_format, e))?;
+        let time = u16::try_from(milli)
+            .ok()
+            .and_then(|milli| time.replace_millisecond(milli).ok())
+            .ok_or_else(|| OffsetDateTimeError::ParseErrorDetailed {
+                input: milli.to_string(),
+                position: None,
+                message: "milliseconds must be in 0..1000".to_string(),
+            })?;
+
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+
+    fn from_simple_detailed(
+        dt: &str,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        let format = fd!("[year][month][day]_[hour][minute]");
+        let parsed = PrimitiveDateTime::parse(dt, format)
+            .map_err(|e| parse_error_detailed(dt, format, e))?;
+        Ok(parsed.assume_offset(offset))
+    }
+
+    fn from_pattern(s: &str, pattern: &str) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        format_cache::with_format(pattern, |format| OffsetDateTime::parse(s, format))?
+            .map_err(|e| OffsetDateTimeError::ParseError(e.to_string()))
+    }
+
+    fn from_pattern_detailed(
+        s: &str,
+        pattern: &str,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        format_cache::with_format(pattern, |format| {
+            OffsetDateTime::parse(s, format).map_err(|e| parse_error_detailed_owned(s, format, e))
+        })?
+    }
+
+    fn try_parse_any(input: &str) -> Result<OffsetDateTime, ParseFailure> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(ParseFailure::Empty);
+        }
+
+        if let Ok(dt) = OffsetDateTime::parse(input, &Rfc3339) {
+            return Ok(dt);
+        }
+        if let Ok(dt) = OffsetDateTime::parse(input, DISPLAY_FORMAT) {
+            return Ok(dt);
+        }
+        if let Ok(ts) = input.parse::<i64>()
+            && let Ok(dt) = OffsetDateTime::from_unix_timestamp(ts)
+        {
+            return Ok(dt);
+        }
+
+        if matches_known_shape(input) {
+            Err(ParseFailure::OutOfRange)
+        } else {
+            Err(ParseFailure::UnknownFormat)
+        }
+    }
+
     fn convert_to_dot_date(input: &str) -> Result<String, OffsetDateTimeError> {
         let parse_format = fd!("[year][month][day]");
         let date = time::Date::parse(input, &parse_format)
@@ -248,11 +1396,41 @@ impl ExtOffsetDateTime for OffsetDateTime {
             .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
     }
 
+    fn convert_to_dot_date_detailed(input: &str) -> Result<String, OffsetDateTimeError> {
+        let parse_format = fd!("[year][month][day]");
+        let date = time::Date::parse(input, &parse_format)
+            .map_err(|e| parse_error_detailed(input, parse_format, e))?;
+
+        let output_format = fd!("[year].[month].[day]");
+        date.format(&output_format)
+            .map_err(|e| OffsetDateTimeError::FormatError(e.to_string()))
+    }
+
+    fn today_at_seconds(
+        seconds: i64,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        OffsetDateTime::now_utc()
+            .to_offset_hours_checked(offset_hours)?
+            .replace_time_with_seconds(seconds)
+    }
+
+    fn from_date_and_seconds(
+        date: Date,
+        seconds: i64,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        PrimitiveDateTime::new(date, Time::MIDNIGHT)
+            .assume_offset(offset)
+            .replace_time_with_seconds(seconds)
+    }
+
     fn replace_time_with_seconds(
         &self,
         seconds: i64,
     ) -> Result<OffsetDateTime, OffsetDateTimeError> {
-        if seconds < 0 || seconds >= 24 * 3600 {
+        if !(0..24 * 3600).contains(&seconds) {
             return Err(OffsetDateTimeError::InvalidSeconds(seconds));
         }
 
@@ -266,41 +1444,190 @@ impl ExtOffsetDateTime for OffsetDateTime {
         Ok(self.replace_time(time))
     }
 
+    fn set_time_str(&self, s: &str, allow_24: bool) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        if allow_24 && matches!(s, "24:00" | "24:00:00") {
+            return Ok(self.replace_time(Time::MIDNIGHT) + Duration::days(1));
+        }
+
+        let time = <Time as ExtTime>::from_str(s)
+            .or_else(|_| Time::from_str_subsec(s))
+            .map_err(|e| OffsetDateTimeError::ParseError(e.to_string()))?;
+
+        Ok(self.replace_time(time))
+    }
+
+    fn replace_time_with_millis(&self, millis: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        if !(0..24 * 3600 * 1000).contains(&millis) {
+            return Err(OffsetDateTimeError::InvalidMillisecondsOfDay(millis));
+        }
+
+        let seconds = millis / 1000;
+        let hours = (seconds / 3600) as u8;
+        let minutes = ((seconds % 3600) / 60) as u8;
+        let secs = (seconds % 60) as u8;
+
+        let time = Time::from_hms_milli(hours, minutes, secs, (millis % 1000) as u16)
+            .map_err(|_| OffsetDateTimeError::InvalidMillisecondsOfDay(millis))?;
+
+        Ok(self.replace_time(time))
+    }
+
+    fn with_hour(&self, hour: u8) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let time = Time::from_hms_nano(hour, self.minute(), self.second(), self.nanosecond())
+            .map_err(|_| OffsetDateTimeError::InvalidComponent("hour", hour))?;
+        Ok(self.replace_time(time))
+    }
+
+    fn with_minute(&self, minute: u8) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let time = Time::from_hms_nano(self.hour(), minute, self.second(), self.nanosecond())
+            .map_err(|_| OffsetDateTimeError::InvalidComponent("minute", minute))?;
+        Ok(self.replace_time(time))
+    }
+
+    fn with_second(&self, second: u8) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let time = Time::from_hms_nano(self.hour(), self.minute(), second, self.nanosecond())
+            .map_err(|_| OffsetDateTimeError::InvalidComponent("second", second))?;
+        Ok(self.replace_time(time))
+    }
+
     fn align_to(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
         if interval == 0 {
-            return Err(OffsetDateTimeError::InvalidAlignmentUnit(
-                interval.abs() as u64
-            ));
+            return Err(OffsetDateTimeError::InvalidAlignmentUnit(0));
+        }
+
+        // Boundaries are the same for either sign; intervals past a day all floor to 00:00
+        self.align_to_floor(interval.unsigned_abs().min(86400) as i64)
+    }
+
+    fn align_to_mode(
+        &self,
+        interval: i64,
+        mode: AlignMode,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        if interval <= 0 {
+            return Err(OffsetDateTimeError::InvalidInterval(interval));
         }
 
-        let total_seconds =
-            self.hour() as i64 * 3600 + self.minute() as i64 * 60 + self.second() as i64;
-        let aligned_seconds = (total_seconds / interval) * interval;
+        const NANOS_PER_SECOND: i64 = 1_000_000_000;
+        const NANOS_PER_DAY: i64 = 86400 * NANOS_PER_SECOND;
+        let interval_nanos = interval.min(86400) * NANOS_PER_SECOND;
+        let current = self.time().to_seconds() * NANOS_PER_SECOND + self.nanosecond() as i64;
+        let floor = current / interval_nanos * interval_nanos;
+        let ceil = if floor == current {
+            floor
+        } else {
+            (floor + interval_nanos).min(NANOS_PER_DAY)
+        };
 
-        let hours = (aligned_seconds / 3600) as u8;
-        let minutes = ((aligned_seconds % 3600) / 60) as u8;
-        let secs = (aligned_seconds % 60) as u8;
+        let aligned = match mode {
+            AlignMode::Floor => floor,
+            AlignMode::Ceil => ceil,
+            AlignMode::Round if ceil - current <= current - floor => ceil,
+            AlignMode::Round => floor,
+        };
 
-        let time = Time::from_hms(hours, minutes, secs)
-            .map_err(|_| OffsetDateTimeError::InvalidSeconds(aligned_seconds))?;
+        // Adding to midnight carries a full-day result into the next date
+        Ok(self.replace_time(Time::MIDNIGHT) + Duration::nanoseconds(aligned))
+    }
 
-        Ok(self.replace_time(time))
+    fn snap_to(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        if interval <= 0 {
+            return Err(OffsetDateTimeError::InvalidInterval(interval));
+        }
+
+        let interval_nanos = interval as i128 * 1_000_000_000;
+        let nanos = self.unix_timestamp_nanos();
+        let floor = nanos.div_euclid(interval_nanos) * interval_nanos;
+        let snapped = if (nanos - floor) * 2 > interval_nanos {
+            floor + interval_nanos
+        } else {
+            floor
+        };
+
+        OffsetDateTime::from_unix_timestamp_nanos(snapped)
+            .map(|dt| dt.to_offset(self.offset()))
+            .map_err(|_| OffsetDateTimeError::InvalidTimestamp((snapped / 1_000_000_000) as i64))
+    }
+
+    fn floor_to(&self, unit: Duration) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        if !unit.is_positive() {
+            return Err(OffsetDateTimeError::InvalidDuration(unit.to_string()));
+        }
+
+        let unit_nanos = unit.whole_nanoseconds();
+        let nanos = self.unix_timestamp_nanos();
+        let floor = nanos.div_euclid(unit_nanos) * unit_nanos;
+
+        OffsetDateTime::from_unix_timestamp_nanos(floor)
+            .map(|dt| dt.to_offset(self.offset()))
+            .map_err(|_| OffsetDateTimeError::InvalidTimestamp((floor / 1_000_000_000) as i64))
+    }
+
+    fn ceil_to(&self, unit: Duration) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let floor = self.floor_to(unit)?;
+        if floor == *self {
+            return Ok(floor);
+        }
+
+        floor
+            .checked_add(unit)
+            .ok_or(OffsetDateTimeError::AddTimeError(floor))
+    }
+
+    fn prev_aligned(&self, interval: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        if interval <= 0 {
+            return Err(OffsetDateTimeError::InvalidInterval(interval));
+        }
+
+        let prev = self
+            .time()
+            .prev_aligned(interval)
+            .map_err(|_| OffsetDateTimeError::InvalidInterval(interval))?;
+        if self.time() == Time::MIDNIGHT {
+            Ok((*self - Duration::days(1)).replace_time(prev))
+        } else {
+            Ok(self.replace_time(prev))
+        }
     }
 
     fn next_day(&self) -> OffsetDateTime {
-        self.clone() + Duration::days(1)
+        *self + Duration::days(1)
     }
 
     fn next_hour(&self) -> OffsetDateTime {
-        self.clone() + Duration::hours(1)
+        *self + Duration::hours(1)
     }
 
     fn next_minute(&self) -> OffsetDateTime {
-        self.clone() + Duration::minutes(1)
+        *self + Duration::minutes(1)
     }
 
     fn next_second(&self) -> OffsetDateTime {
-        self.clone() + Duration::seconds(1)
+        *self + Duration::seconds(1)
+    }
+
+    fn prev_day(&self) -> OffsetDateTime {
+        *self - Duration::days(1)
+    }
+
+    fn prev_hour(&self) -> OffsetDateTime {
+        *self - Duration::hours(1)
+    }
+
+    fn prev_minute(&self) -> OffsetDateTime {
+        *self - Duration::minutes(1)
+    }
+
+    fn prev_second(&self) -> OffsetDateTime {
+        *self - Duration::seconds(1)
+    }
+
+    fn nth_tick(&self, interval: i64, n: i64) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let seconds = interval
+            .checked_mul(n)
+            .ok_or(OffsetDateTimeError::AddTimeError(*self))?;
+        self.checked_add(Duration::seconds(seconds))
+            .ok_or(OffsetDateTimeError::AddTimeError(*self))
     }
 
     fn to_hour_seconds(&self) -> i64 {
@@ -312,12 +1639,14 @@ impl ExtOffsetDateTime for OffsetDateTime {
     }
 
     fn duration_to_time(&self, target_hour: u8, target_minute: u8, target_second: u8) -> Duration {
-        // Create target time in the same date and timezone as current time
         let target_time = Time::from_hms(target_hour, target_minute, target_second)
             .expect("Invalid target time components");
+        self.duration_to(target_time)
+    }
 
-        // Create target datetime for today
-        let target_today = self.replace_time(target_time);
+    fn duration_to(&self, target: Time) -> Duration {
+        // Create target datetime for today in the same timezone as current time
+        let target_today = self.replace_time(target);
 
         // Calculate duration to target time today
         let duration_to_today = target_today - *self;
@@ -331,4 +1660,252 @@ impl ExtOffsetDateTime for OffsetDateTime {
             target_tomorrow - *self
         }
     }
+
+    fn countdown_to(&self, target: Time) -> String {
+        let seconds = self
+            .duration_to_time(target.hour(), target.minute(), target.second())
+            .whole_seconds();
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            (seconds % 3600) / 60,
+            seconds % 60
+        )
+    }
+
+    fn until_next_day(&self) -> Duration {
+        let next_midnight = (*self + Duration::days(1)).replace_time(Time::MIDNIGHT);
+        next_midnight - *self
+    }
+
+    fn seconds_in_day() -> i64 {
+        Duration::DAY.whole_seconds()
+    }
+
+    fn seconds_remaining_today(&self) -> i64 {
+        self.until_next_day().whole_seconds()
+    }
+
+    fn window_coverage(&self, window_start: OffsetDateTime, window_end: OffsetDateTime) -> f64 {
+        let from = window_start.max(*self);
+        let to = window_end.min(self.next_day());
+        if to <= from {
+            return 0.0;
+        }
+
+        (to - from).as_seconds_f64() / Duration::DAY.as_seconds_f64()
+    }
+
+    fn within_last(&self, d: Duration, now: OffsetDateTime) -> bool {
+        now - d <= *self && *self <= now
+    }
+
+    fn within_next(&self, d: Duration, now: OffsetDateTime) -> bool {
+        now <= *self && *self <= now + d
+    }
+
+    fn clamp_dt(&self, min: OffsetDateTime, max: OffsetDateTime) -> OffsetDateTime {
+        if *self < min {
+            min
+        } else if *self > max {
+            max
+        } else {
+            *self
+        }
+    }
+
+    fn is_first_of_month(&self) -> bool {
+        self.day() == 1
+    }
+
+    fn is_last_of_month(&self) -> bool {
+        self.day() == helper::days_in_month(self.year(), self.month())
+    }
+
+    fn start_of_week(&self, first_day: Weekday) -> OffsetDateTime {
+        let days_back =
+            (helper::weekday_to_u8(self.weekday()) + 7 - helper::weekday_to_u8(first_day)) % 7;
+        (*self - Duration::days(days_back as i64)).replace_time(Time::MIDNIGHT)
+    }
+
+    fn same_week(&self, other: &OffsetDateTime, first_day: Weekday) -> bool {
+        self.start_of_week(first_day).date()
+            == other
+                .to_offset(self.offset())
+                .start_of_week(first_day)
+                .date()
+    }
+
+    fn start_of_quarter(&self) -> OffsetDateTime {
+        let first_month = (self.month() as u8 - 1) / 3 * 3 + 1;
+        let month = Month::try_from(first_month).expect("Invalid quarter month");
+        let date =
+            Date::from_calendar_date(self.year(), month, 1).expect("Invalid quarter start date");
+        self.replace_date(date).replace_time(Time::MIDNIGHT)
+    }
+
+    fn end_of_quarter(&self) -> OffsetDateTime {
+        let last_month = (self.month() as u8 - 1) / 3 * 3 + 3;
+        let month = Month::try_from(last_month).expect("Invalid quarter month");
+        let last_day = helper::days_in_month(self.year(), month);
+        let date = Date::from_calendar_date(self.year(), month, last_day)
+            .expect("Invalid quarter end date");
+        let time = Time::from_hms_nano(23, 59, 59, 999_999_999).expect("Invalid end of day time");
+        self.replace_date(date).replace_time(time)
+    }
+
+    fn to_system_time(&self) -> Result<SystemTime, OffsetDateTimeError> {
+        let since_epoch = self.duration_since_epoch();
+        if since_epoch.is_negative() {
+            UNIX_EPOCH.checked_sub(since_epoch.unsigned_abs())
+        } else {
+            UNIX_EPOCH.checked_add(since_epoch.unsigned_abs())
+        }
+        .ok_or(OffsetDateTimeError::SystemTimeOutOfRange)
+    }
+
+    fn from_system_time(
+        t: SystemTime,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+
+        let since_epoch = match t.duration_since(UNIX_EPOCH) {
+            Ok(d) => Duration::try_from(d),
+            // Pre-epoch: the error carries how far before the epoch `t` is
+            Err(e) => Duration::try_from(e.duration()).map(|d| -d),
+        }
+        .map_err(|_| OffsetDateTimeError::SystemTimeOutOfRange)?;
+
+        let dt = OffsetDateTime::UNIX_EPOCH
+            .checked_add(since_epoch)
+            .ok_or(OffsetDateTimeError::SystemTimeOutOfRange)?;
+
+        Ok(dt.to_offset(offset))
+    }
+
+    fn calendar_days_between(&self, other: &OffsetDateTime) -> i64 {
+        (other.date() - self.date()).whole_days()
+    }
+
+    fn diff_components(&self, other: &OffsetDateTime) -> (i64, u8, u8, u8) {
+        let seconds = (*other - *self).whole_seconds();
+        let days = seconds.div_euclid(86400);
+        let rest = seconds.rem_euclid(86400);
+
+        (
+            days,
+            (rest / 3600) as u8,
+            (rest % 3600 / 60) as u8,
+            (rest % 60) as u8,
+        )
+    }
+
+    fn age_since(&self, past: OffsetDateTime) -> Result<(i64, u8, u8), OffsetDateTimeError> {
+        if past >= *self {
+            return Ok((0, 0, 0));
+        }
+
+        let past = past
+            .checked_to_offset(self.offset())
+            .ok_or(OffsetDateTimeError::AddTimeError(past))?;
+        let shift = |months: i64| {
+            helper::add_months_clamped(past.date(), months)
+                .map(|date| past.replace_date(date))
+                .ok_or(OffsetDateTimeError::AddTimeError(past))
+        };
+
+        let mut months = (self.year() as i64 - past.year() as i64) * 12
+            + (self.month() as i64 - past.month() as i64);
+        let mut anchor = shift(months)?;
+        if anchor > *self {
+            months -= 1;
+            anchor = shift(months)?;
+        }
+        let days = (*self - anchor).whole_days();
+
+        Ok((months / 12, (months % 12) as u8, days as u8))
+    }
+
+    fn from_iso_week(
+        year: i32,
+        week: u8,
+        weekday: Weekday,
+        time: Time,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        let date = Date::from_iso_week_date(year, week, weekday)
+            .map_err(|_| OffsetDateTimeError::InvalidIsoWeek(year, week))?;
+
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(offset))
+    }
+
+    fn to_iso_week_string(&self) -> String {
+        let (year, week, weekday) = self.to_iso_week_date();
+        format!("{:04}-W{:02}-{}", year, week, weekday.number_from_monday())
+    }
+
+    fn from_iso_week_string(
+        s: &str,
+        offset_hours: i8,
+    ) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        let format = fd!("[year base:iso_week]-W[week_number repr:iso]-[weekday repr:monday]");
+        let date = Date::parse(s, format).map_err(|e| parse_error_detailed(s, format, e))?;
+
+        Ok(PrimitiveDateTime::new(date, Time::MIDNIGHT).assume_offset(offset))
+    }
+
+    fn is_business_day(&self, holidays: &HashSet<Date>) -> bool {
+        !matches!(self.weekday(), Weekday::Saturday | Weekday::Sunday)
+            && !holidays.contains(&self.date())
+    }
+
+    fn business_days_between(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        holidays: &HashSet<Date>,
+    ) -> impl Iterator<Item = OffsetDateTime> {
+        std::iter::successors(Some(start), |day| day.checked_add(Duration::days(1)))
+            .take_while(move |day| *day <= end)
+            .filter(move |day| day.is_business_day(holidays))
+    }
+
+    fn business_seconds_between(
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        open: Time,
+        close: Time,
+        holidays: &HashSet<Date>,
+    ) -> i64 {
+        if end <= start {
+            return 0;
+        }
+
+        let end = end.to_offset(start.offset());
+        let session_length = close.sub_ext(open);
+        let session_length = if session_length.is_zero() {
+            Duration::days(1)
+        } else {
+            session_length
+        };
+
+        // Start one day early to catch an overnight session opened the day before
+        let mut day = start.replace_time(open) - Duration::days(1);
+        let mut total = Duration::ZERO;
+        while day < end {
+            if day.is_business_day(holidays) {
+                let session_end = day + session_length;
+                let from = day.max(start);
+                let to = session_end.min(end);
+                if to > from {
+                    total += to - from;
+                }
+            }
+            day += Duration::days(1);
+        }
+
+        total.whole_seconds()
+    }
 }
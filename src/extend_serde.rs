@@ -1,5 +1,10 @@
-use serde::{de, Deserialize, Deserializer, Serializer};
-use time::OffsetDateTime;
+use serde::{
+    Deserialize, Deserializer, Serializer, de,
+    ser::{SerializeStruct, SerializeTuple},
+};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::{ExtOffsetDateTime, extend_offset_time::DISPLAY_FORMAT};
 
 /// serde serialize OffsetDateTime to Timestamp
 ///
@@ -21,3 +26,195 @@ where
     let t: i64 = Deserialize::deserialize(deserializer)?;
     OffsetDateTime::from_unix_timestamp(t).map_err(de::Error::custom)
 }
+
+/// serde serialize Option<OffsetDateTime> to Timestamp, with `None` as null
+///
+/// `#[serde(serialize_with = "serde_t2ts_opt")]`
+pub fn serde_t2ts_opt<S>(x: &Option<OffsetDateTime>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match x {
+        Some(dt) => s.serialize_some(&dt.unix_timestamp()),
+        None => s.serialize_none(),
+    }
+}
+
+/// serde deserialize a nullable Timestamp to Option<OffsetDateTime>
+///
+/// Null deserializes to `None`. Serde only calls this for fields that are present,
+/// so add `default` to also map a missing field to `None`:
+///
+/// `#[serde(default, deserialize_with = "serde_parse_ts_opt")]`
+pub fn serde_parse_ts_opt<'de, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let t: Option<i64> = Deserialize::deserialize(deserializer)?;
+    t.map(|t| OffsetDateTime::from_unix_timestamp(t).map_err(de::Error::custom))
+        .transpose()
+}
+
+/// serde serialize OffsetDateTime to Timestamp in milliseconds
+///
+/// Subseconds below a millisecond are truncated.
+///
+/// `#[serde(serialize_with = "serde_t2ts_millis")]`
+pub fn serde_t2ts_millis<S>(x: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_i64(x.milli_timestamp())
+}
+
+/// serde deserialize Timestamp in milliseconds to OffsetDateTime in UTC
+///
+/// Negative (pre-1970) timestamps are supported.
+///
+/// `#[serde(deserialize_with = "serde_parse_ts_millis")]`
+pub fn serde_parse_ts_millis<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let t: i64 = Deserialize::deserialize(deserializer)?;
+    let seconds = t.div_euclid(1000);
+    let millis = t.rem_euclid(1000) as u16;
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .and_then(|dt| dt.replace_millisecond(millis))
+        .map_err(de::Error::custom)
+}
+
+/// serde serialize OffsetDateTime to Timestamp in nanoseconds (i128)
+///
+/// Unlike `serde_t2ts`, subseconds are preserved. Note that JSON numbers are
+/// commonly parsed as f64 by consumers, which cannot represent nanosecond
+/// timestamps exactly; prefer `serde_t2ts_nanos_str` for such consumers.
+///
+/// `#[serde(serialize_with = "serde_t2ts_nanos")]`
+pub fn serde_t2ts_nanos<S>(x: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_i128(x.unix_timestamp_nanos())
+}
+
+/// serde deserialize Timestamp in nanoseconds (i128) to OffsetDateTime
+///
+/// `#[serde(deserialize_with = "serde_parse_ts_nanos")]`
+pub fn serde_parse_ts_nanos<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let t: i128 = Deserialize::deserialize(deserializer)?;
+    OffsetDateTime::from_unix_timestamp_nanos(t).map_err(de::Error::custom)
+}
+
+/// serde serialize OffsetDateTime to Timestamp in nanoseconds as a string
+///
+/// `#[serde(serialize_with = "serde_t2ts_nanos_str")]`
+pub fn serde_t2ts_nanos_str<S>(x: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_str(&x.unix_timestamp_nanos().to_string())
+}
+
+/// serde deserialize Timestamp in nanoseconds from a string to OffsetDateTime
+///
+/// `#[serde(deserialize_with = "serde_parse_ts_nanos_str")]`
+pub fn serde_parse_ts_nanos_str<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    let t: i128 = s.parse().map_err(de::Error::custom)?;
+    OffsetDateTime::from_unix_timestamp_nanos(t).map_err(de::Error::custom)
+}
+
+/// serde serialize OffsetDateTime to an object with Timestamp and human readable string
+///
+/// Produces `{ "ts": 1710484245, "human": "2024-03-15 14:30:45+08:00" }`, with the
+/// string in the datetime's own offset.
+///
+/// `#[serde(serialize_with = "serde_t2ts_and_str")]`
+pub fn serde_t2ts_and_str<S>(x: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let human = x
+        .format(DISPLAY_FORMAT)
+        .map_err(serde::ser::Error::custom)?;
+
+    let mut state = s.serialize_struct("TimestampWithHuman", 2)?;
+    state.serialize_field("ts", &x.unix_timestamp())?;
+    state.serialize_field("human", &human)?;
+    state.end()
+}
+
+/// serde deserialize an object with Timestamp to OffsetDateTime, reading only the `ts` field
+///
+/// `#[serde(deserialize_with = "serde_parse_ts_and_str")]`
+pub fn serde_parse_ts_and_str<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct TimestampWithHuman {
+        ts: i64,
+    }
+
+    let t = TimestampWithHuman::deserialize(deserializer)?;
+    OffsetDateTime::from_unix_timestamp(t.ts).map_err(de::Error::custom)
+}
+
+/// serde serialize OffsetDateTime to a `[unix_secs, nanos]` array
+///
+/// Lossless alternative to `serde_t2ts`, keeping subseconds as an integer.
+///
+/// `#[serde(serialize_with = "serde_t2ts_pair")]`
+pub fn serde_t2ts_pair<S>(x: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut tuple = s.serialize_tuple(2)?;
+    tuple.serialize_element(&x.unix_timestamp())?;
+    tuple.serialize_element(&x.nanosecond())?;
+    tuple.end()
+}
+
+/// serde deserialize a `[unix_secs, nanos]` array to OffsetDateTime
+///
+/// `#[serde(deserialize_with = "serde_parse_ts_pair")]`
+pub fn serde_parse_ts_pair<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (secs, nanos): (i64, u32) = Deserialize::deserialize(deserializer)?;
+    OffsetDateTime::from_unix_timestamp(secs)
+        .and_then(|dt| dt.replace_nanosecond(nanos))
+        .map_err(de::Error::custom)
+}
+
+/// serde serialize OffsetDateTime to an RFC 3339 string, e.g. "2024-03-15T12:00:00+08:00"
+///
+/// The datetime's own offset is kept.
+///
+/// `#[serde(serialize_with = "serde_t2rfc3339")]`
+pub fn serde_t2rfc3339<S>(x: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let formatted = x.format(&Rfc3339).map_err(serde::ser::Error::custom)?;
+    s.serialize_str(&formatted)
+}
+
+/// serde deserialize an RFC 3339 string to OffsetDateTime, preserving the input's offset
+///
+/// `#[serde(deserialize_with = "serde_parse_rfc3339")]`
+pub fn serde_parse_rfc3339<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    OffsetDateTime::parse(&s, &Rfc3339).map_err(de::Error::custom)
+}
@@ -1,6 +1,12 @@
+use std::collections::HashMap;
 use std::ops::Sub;
 use thiserror::Error;
-use time::{Duration, Time, ext::NumericalDuration};
+use time::{
+    Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, ext::NumericalDuration,
+    macros::format_description as fd,
+};
+
+use crate::{OffsetDateTimeError, TimeRange, helper};
 
 #[derive(Error, Debug)]
 pub enum TimeError {
@@ -16,6 +22,40 @@ pub enum TimeError {
     InvalidAlignmentUnit(u64),
     #[error("Failed to add time: {0:?}")]
     AddTimeError(Time),
+    #[error("Invalid interval: {0}, must be positive")]
+    InvalidInterval(i64),
+    #[error("Unknown timezone abbreviation: {0}")]
+    UnknownZone(String),
+}
+
+/// Where a time falls relative to a window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Position {
+    Before,
+    Inside,
+    After,
+}
+
+/// Rounding mode used when aligning to an interval grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignMode {
+    /// Round down to the previous boundary
+    Floor,
+    /// Round up to the next boundary, keeping values already on a boundary
+    Ceil,
+    /// Round to the nearest boundary, ties rounding up
+    Round,
+}
+
+/// Sub-second precision level used when truncating a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubUnit {
+    /// Keep milliseconds, drop micro- and nanoseconds
+    Milli,
+    /// Keep microseconds, drop nanoseconds
+    Micro,
+    /// Drop all subseconds
+    Second,
 }
 
 /// Extension trait for Time struct providing additional utility methods
@@ -32,6 +72,11 @@ pub trait ExtTime {
     /// ```
     fn to_shorten(&self) -> String;
 
+    /// Format time as zero-padded `HH:MM:SS`, always 8 characters wide
+    ///
+    /// Subseconds are dropped, which keeps columns aligned in tabular output.
+    fn to_fixed_width(&self) -> String;
+
     /// Parse time string in HH:MM format
     ///
     /// # Arguments
@@ -42,6 +87,40 @@ pub trait ExtTime {
     /// * `Err` - If parsing fails
     fn from_str(time_str: &str) -> Result<Time, TimeError>;
 
+    /// Parse time string in "HH:MM ZONE" format, resolving the zone with a caller-provided map
+    ///
+    /// # Arguments
+    /// * `time_str` - Time string like "14:30 CST"
+    /// * `zones` - Map from zone abbreviation to offset hours from UTC
+    ///
+    /// # Returns
+    /// * `Ok((Time, i8))` - Parsed time and resolved offset hours
+    /// * `Err` - If parsing fails or the zone is not in `zones`
+    fn from_str_with_known_zone(
+        time_str: &str,
+        zones: &HashMap<&str, i8>,
+    ) -> Result<(Time, i8), TimeError>;
+
+    /// Parse time string in HH:MM:SS format with optional fractional seconds
+    ///
+    /// # Arguments
+    /// * `time_str` - Time string like "14:30:45" or "14:30:45.250" (up to 9 fractional digits)
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Parsed time
+    /// * `Err` - If parsing fails
+    fn from_str_subsec(time_str: &str) -> Result<Time, TimeError>;
+
+    /// Parse a strict ISO 8601 time of day, `HH:MM:SS` with optional fractional seconds
+    ///
+    /// Unlike `from_str_subsec`, every component must be two digits, e.g. "14:30:45"
+    /// or "14:30:45.123"; "9:05:00" is rejected.
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Parsed time
+    /// * `Err` - If the string is not a valid ISO time
+    fn from_iso_time(time_str: &str) -> Result<Time, TimeError>;
+
     /// Calculate duration between two times, handling cross-day scenarios
     ///
     /// # Arguments
@@ -51,16 +130,96 @@ pub trait ExtTime {
     /// Duration between times, always positive by adding 24 hours if needed
     fn sub_ext(&self, right: Time) -> Duration;
 
+    /// Calculate duration between two times like `sub_ext`, but treat equal times as a full day
+    ///
+    /// Useful when "equal" means the next occurrence is 24 hours away.
+    ///
+    /// # Arguments
+    /// * `right` - The time to subtract from self
+    ///
+    /// # Returns
+    /// Duration between times, in (0, 24h]
+    fn sub_ext_full(&self, right: Time) -> Duration;
+
+    /// Interpolate between self and `other` at fraction `t`
+    ///
+    /// Follows the forward (cross-day aware) path from self to `other`, so
+    /// 23:00 to 01:00 at 0.5 gives 00:00. `t` is clamped to [0, 1].
+    fn lerp(&self, other: Time, t: f64) -> Time;
+
+    /// Get the hour hand angle on a 12-hour clock face in degrees, clockwise from 12
+    ///
+    /// The hand moves continuously, so 1:30 gives 45.0.
+    ///
+    /// # Returns
+    /// Angle in [0, 360)
+    fn hour_angle(&self) -> f64;
+
+    /// Get the minute hand angle in degrees, clockwise from 12, including seconds
+    ///
+    /// # Returns
+    /// Angle in [0, 360)
+    fn minute_angle(&self) -> f64;
+
+    /// Get the second hand angle in degrees, clockwise from 12, including subseconds
+    ///
+    /// # Returns
+    /// Angle in [0, 360)
+    fn second_angle(&self) -> f64;
+
+    /// Combine time with a date and timezone offset (hours from UTC) into a datetime
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Datetime at self on `date`
+    /// * `Err` - If offset is invalid
+    fn on_date(&self, date: Date, offset_hours: i8) -> Result<OffsetDateTime, OffsetDateTimeError>;
+
     /// Reset seconds to zero, keeping hours and minutes
     fn reset_minute(&self) -> Result<Time, TimeError>;
 
+    /// Zero all precision below the given unit
+    ///
+    /// # Arguments
+    /// * `unit` - Finest unit to keep
+    fn truncate_to_unit(&self, unit: SubUnit) -> Time;
+
     /// Check if two times are in the same minute
     fn is_same_minute(&self, other: &Time) -> bool;
 
+    /// Check if time is exactly on a minute (second and subsecond are zero)
+    fn is_whole_minute(&self) -> bool;
+
+    /// Check if time is exactly on an hour (minute, second and subsecond are zero)
+    fn is_whole_hour(&self) -> bool;
+
     /// Check if time is between start and end (inclusive)
     /// Handles cross-day ranges (e.g., 23:00 to 01:00)
     fn is_between(&self, start: Time, end: Time) -> bool;
 
+    /// Check if time is inside the range, see `TimeRange::contains`
+    fn is_in(&self, range: &TimeRange) -> bool;
+
+    /// Get where time falls relative to the window from start to end (inclusive)
+    ///
+    /// Cross-day windows (e.g., 23:00 to 01:00) are taken to open on the current day,
+    /// so times in the gap between `end` and `start` are `Before` the window.
+    fn position_in(&self, start: Time, end: Time) -> Position;
+
+    /// Check if time is inside any of the windows (each inclusive and cross-day aware)
+    fn is_in_any(&self, windows: &[(Time, Time)]) -> bool;
+
+    /// Get the label of the first window containing the time
+    ///
+    /// Windows are inclusive and cross-day aware like `is_between`, and are checked
+    /// in order, so earlier entries win where windows overlap.
+    ///
+    /// # Arguments
+    /// * `windows` - `(label, start, end)` entries, e.g. `("pre-market", 4:00, 9:30)`
+    ///
+    /// # Returns
+    /// The matching label, or `None` if no window contains the time
+    fn classify(&self, windows: &[(&'static str, Time, Time)]) -> Option<&'static str>;
+
     /// Add minutes to time, wrapping around midnight if needed
     fn add_minutes(&self, minutes: i64) -> Time;
 
@@ -74,22 +233,101 @@ pub trait ExtTime {
     /// * `Err` - If seconds value is invalid
     fn from_seconds(seconds: i64) -> Result<Time, TimeError>;
 
+    /// Convert seconds to Time, wrapping negative or out-of-day values around midnight
+    ///
+    /// Unlike `from_seconds`, which rejects values outside 0..86400, this applies
+    /// `rem_euclid(86400)` first, so -1 becomes 23:59:59 and 86400 becomes 00:00:00.
+    fn from_seconds_wrapping(seconds: i64) -> Time;
+
+    /// Convert a duration since midnight to a Time, reporting whole days of overflow
+    ///
+    /// Subseconds are kept. The carry is negative for negative durations, e.g.
+    /// 25h gives (01:00, 1) and -1h gives (23:00, -1).
+    ///
+    /// # Returns
+    /// The wrapped time and the number of whole days carried
+    fn from_duration_since_midnight(d: Duration) -> (Time, i64);
+
     /// Convert Time to seconds (hours + minutes + seconds)
     ///
     /// # Returns
     /// Total seconds (hours * 3600 + minutes * 60 + seconds)
     fn to_seconds(&self) -> i64;
 
-    /// Align time to the nearest interval
+    /// Get minutes since midnight (hours * 60 + minutes)
+    fn minutes_of_day(&self) -> u32;
+
+    /// Get seconds since midnight (hours * 3600 + minutes * 60 + seconds)
+    ///
+    /// Same as `to_seconds`, but unsigned.
+    fn total_seconds_of_day(&self) -> u32;
+
+    /// Align time down to the interval
+    ///
+    /// Delegates to `align_to_floor`, kept for backward compatibility. A negative
+    /// interval is treated as its absolute value, and 0 is reported as
+    /// `InvalidAlignmentUnit` rather than `InvalidInterval`.
     ///
     /// # Arguments
-    /// * `interval` - Interval in seconds (can be negative for backward alignment)
+    /// * `interval` - Interval in seconds (sign is ignored)
     ///
     /// # Returns
     /// * `Ok(Time)` - Aligned time
     /// * `Err(Error)` - If interval is 0
     fn align_to(&self, interval: i64) -> Result<Time, TimeError>;
 
+    /// Align time down to the previous interval boundary (or keep it if already aligned)
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Aligned time
+    /// * `Err` - If interval is not positive
+    fn align_to_floor(&self, interval: i64) -> Result<Time, TimeError> {
+        self.round_to(interval, AlignMode::Floor)
+    }
+
+    /// Align time up to the next interval boundary, keeping times already on a boundary
+    ///
+    /// Rounding up past the end of the day wraps to 00:00.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Aligned time
+    /// * `Err` - If interval is not positive
+    fn align_to_ceil(&self, interval: i64) -> Result<Time, TimeError> {
+        self.round_to(interval, AlignMode::Ceil)
+    }
+
+    /// Align time to the nearest interval boundary, ties rounding up
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Aligned time
+    /// * `Err` - If interval is not positive
+    fn align_to_nearest(&self, interval: i64) -> Result<Time, TimeError> {
+        self.round_to(interval, AlignMode::Round)
+    }
+
+    /// Align time to the interval like `align_to`, optionally keeping the subsecond part
+    ///
+    /// `align_to` always drops subseconds; with `keep_subseconds` the fractional part of
+    /// self is carried over, e.g. 14:30:07.250 on a 5-second grid gives 14:30:05.250.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds
+    /// * `keep_subseconds` - Whether the nanosecond component survives alignment
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Aligned time
+    /// * `Err(Error)` - If interval is 0
+    fn align_to_subsec(&self, interval: i64, keep_subseconds: bool) -> Result<Time, TimeError>;
+
     /// Get next day at the same time
     fn next_day(&self) -> Time;
 
@@ -102,6 +340,28 @@ pub trait ExtTime {
     /// Get next second
     fn next_second(&self) -> Time;
 
+    /// Get previous day at the same time
+    fn prev_day(&self) -> Time;
+
+    /// Get previous hour at the same minute, second and subsecond
+    ///
+    /// Wraps to 23 before hour 0.
+    fn prev_hour(&self) -> Time;
+
+    /// Get previous minute at the same second and subsecond
+    fn prev_minute(&self) -> Time;
+
+    /// Get previous second, keeping the subsecond
+    fn prev_second(&self) -> Time;
+
+    /// Get the next 15-minute boundary (:00, :15, :30 or :45), strictly after self
+    ///
+    /// Wraps to 00:00 after 23:45.
+    fn next_quarter_hour(&self) -> Time;
+
+    /// Align time down to the 15-minute boundary at or before self
+    fn align_quarter_hour(&self) -> Time;
+
     /// Convert time to seconds, ignoring minutes and seconds
     ///
     /// # Returns
@@ -117,6 +377,96 @@ pub trait ExtTime {
     ///
     /// Note: Returns i64 to support time differences and negative values
     fn to_minute_seconds(&self) -> i64;
+
+    /// Count whole intervals from self forward to `other`, handling cross-day scenarios
+    ///
+    /// # Arguments
+    /// * `other` - The end time
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(i64)` - Number of whole intervals between the two times
+    /// * `Err` - If interval is not positive
+    fn intervals_until(&self, other: Time, interval: i64) -> Result<i64, TimeError>;
+
+    /// Split time into (hour, minute, second)
+    ///
+    /// # Example
+    /// ```
+    /// use time::macros::time;
+    /// use ext_time::ExtTime;
+    ///
+    /// let t = time!(14:30:45);
+    /// assert_eq!(t.split_hms(), (14, 30, 45));
+    /// ```
+    fn split_hms(&self) -> (u8, u8, u8);
+
+    /// Calculate duration until the next interval boundary within the day
+    ///
+    /// Boundaries are multiples of `interval` counted from midnight. If self is
+    /// exactly on a boundary, the following one is returned. When the next
+    /// boundary would fall past the end of the day, the next day's 00:00 is used.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(Duration)` - Duration until the next boundary
+    /// * `Err` - If interval is not positive
+    fn until_next_boundary(&self, interval: i64) -> Result<Duration, TimeError>;
+
+    /// Get the interval boundaries surrounding self as (floor, ceil)
+    ///
+    /// Boundaries are multiples of `interval` counted from midnight. If self is
+    /// exactly on a boundary, both values equal self. A ceil past the end of the
+    /// day wraps to 00:00.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok((Time, Time))` - The lower and upper boundaries
+    /// * `Err` - If interval is not positive
+    fn surrounding_boundaries(&self, interval: i64) -> Result<(Time, Time), TimeError>;
+
+    /// Round time to the interval grid using the given mode
+    ///
+    /// Boundaries are multiples of `interval` counted from midnight. Rounding up
+    /// past the end of the day wraps to 00:00.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    /// * `mode` - Floor, ceil or round (half-up)
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - Rounded time
+    /// * `Err` - If interval is not positive
+    fn round_to(&self, interval: i64, mode: AlignMode) -> Result<Time, TimeError>;
+
+    /// Get the largest interval boundary strictly before self
+    ///
+    /// Boundaries are multiples of `interval` counted from midnight. If self is on a
+    /// boundary this steps back one full interval; at 00:00 it wraps to the last
+    /// boundary of the previous day.
+    ///
+    /// # Arguments
+    /// * `interval` - Interval in seconds (must be positive)
+    ///
+    /// # Returns
+    /// * `Ok(Time)` - The previous boundary
+    /// * `Err` - If interval is not positive
+    fn prev_aligned(&self, interval: i64) -> Result<Time, TimeError>;
+
+    /// Add seconds to time, wrapping around midnight if needed and preserving nanoseconds
+    fn add_seconds(&self, seconds: i64) -> Time;
+
+    /// Subtract seconds from time, wrapping around midnight if needed and preserving nanoseconds
+    fn sub_seconds(&self, seconds: i64) -> Time;
+
+    /// Get the `n`th tick of a schedule starting at self, i.e. self + `interval * n` seconds
+    ///
+    /// Wraps around midnight like `add_seconds`; `n` may be negative.
+    fn nth_tick(&self, interval: i64, n: i64) -> Time;
 }
 
 impl ExtTime for Time {
@@ -124,22 +474,85 @@ impl ExtTime for Time {
         format!("{}:{:02}", self.hour(), self.minute())
     }
 
+    fn to_fixed_width(&self) -> String {
+        format!(
+            "{:02}:{:02}:{:02}",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+
     fn from_str(time_str: &str) -> Result<Time, TimeError> {
         let parts: Vec<&str> = time_str.split(':').collect();
-        if parts.len() == 2 {
-            if let (Ok(hour), Ok(minute)) = (parts[0].parse::<u8>(), parts[1].parse::<u8>()) {
-                if hour < 24 && minute < 60 {
-                    return Time::from_hms(hour, minute, 0)
-                        .map_err(|_| TimeError::InvalidComponents(hour, minute));
-                }
-            }
+        if parts.len() == 2
+            && let (Ok(hour), Ok(minute)) = (parts[0].parse::<u8>(), parts[1].parse::<u8>())
+            && hour < 24
+            && minute < 60
+        {
+            return Time::from_hms(hour, minute, 0)
+                .map_err(|_| TimeError::InvalidComponents(hour, minute));
         }
 
         Err(TimeError::InvalidFormat(time_str.to_string()))
     }
 
+    fn from_str_with_known_zone(
+        time_str: &str,
+        zones: &HashMap<&str, i8>,
+    ) -> Result<(Time, i8), TimeError> {
+        let (time_part, zone) = time_str
+            .trim()
+            .rsplit_once(char::is_whitespace)
+            .ok_or_else(|| TimeError::InvalidFormat(time_str.to_string()))?;
+
+        let offset_hours = *zones
+            .get(zone)
+            .ok_or_else(|| TimeError::UnknownZone(zone.to_string()))?;
+        let time = <Time as ExtTime>::from_str(time_part.trim_end())?;
+
+        Ok((time, offset_hours))
+    }
+
+    fn from_iso_time(time_str: &str) -> Result<Time, TimeError> {
+        let format = fd!("[hour]:[minute]:[second][optional [.[subsecond]]]");
+        Time::parse(time_str, format).map_err(|_| TimeError::InvalidFormat(time_str.to_string()))
+    }
+
+    fn from_str_subsec(time_str: &str) -> Result<Time, TimeError> {
+        let invalid = || TimeError::InvalidFormat(time_str.to_string());
+
+        let (hms, fraction) = match time_str.split_once('.') {
+            Some((hms, fraction)) => (hms, Some(fraction)),
+            None => (time_str, None),
+        };
+
+        let parts: Vec<u8> = hms
+            .split(':')
+            .map(|part| part.parse::<u8>().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        let [hour, minute, second] = parts[..] else {
+            return Err(invalid());
+        };
+
+        let nanos = match fraction {
+            Some(digits)
+                if (1..=9).contains(&digits.len())
+                    && digits.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                let value: u32 = digits.parse().map_err(|_| invalid())?;
+                value * 10u32.pow(9 - digits.len() as u32)
+            }
+            Some(_) => return Err(invalid()),
+            None => 0,
+        };
+
+        Time::from_hms_nano(hour, minute, second, nanos)
+            .map_err(|_| TimeError::InvalidComponents(hour, minute))
+    }
+
     fn sub_ext(&self, right: Time) -> Duration {
-        let diff = self.clone().sub(right);
+        let diff = self.sub(right);
         if diff.is_negative() {
             24.hours() + diff
         } else {
@@ -147,15 +560,62 @@ impl ExtTime for Time {
         }
     }
 
+    fn hour_angle(&self) -> f64 {
+        ((self.hour() % 12) as f64 + self.minute_angle() / 360.0) * 30.0
+    }
+
+    fn minute_angle(&self) -> f64 {
+        (self.minute() as f64 + self.second_angle() / 360.0) * 6.0
+    }
+
+    fn second_angle(&self) -> f64 {
+        (self.second() as f64 + self.nanosecond() as f64 / 1_000_000_000.0) * 6.0
+    }
+
+    fn lerp(&self, other: Time, t: f64) -> Time {
+        let t = t.clamp(0.0, 1.0);
+        let span = other.sub_ext(*self).whole_nanoseconds() as f64;
+        *self + Duration::nanoseconds((span * t).round() as i64)
+    }
+
+    fn sub_ext_full(&self, right: Time) -> Duration {
+        let diff = self.sub_ext(right);
+        if diff.is_zero() { 24.hours() } else { diff }
+    }
+
+    fn on_date(&self, date: Date, offset_hours: i8) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let offset = helper::offset_from_hours(offset_hours)?;
+        Ok(PrimitiveDateTime::new(date, *self).assume_offset(offset))
+    }
+
     fn reset_minute(&self) -> Result<Time, TimeError> {
         Time::from_hms(self.hour(), self.minute(), 0)
             .map_err(|_| TimeError::ResetSecondsError(*self))
     }
 
+    fn truncate_to_unit(&self, unit: SubUnit) -> Time {
+        let nanos = self.nanosecond();
+        let kept = match unit {
+            SubUnit::Milli => nanos - nanos % 1_000_000,
+            SubUnit::Micro => nanos - nanos % 1_000,
+            SubUnit::Second => 0,
+        };
+        // Truncating never leaves the valid range, so this cannot fail
+        self.replace_nanosecond(kept).unwrap_or(*self)
+    }
+
     fn is_same_minute(&self, other: &Time) -> bool {
         self.minute() == other.minute() && self.hour() == other.hour()
     }
 
+    fn is_whole_minute(&self) -> bool {
+        self.second() == 0 && self.nanosecond() == 0
+    }
+
+    fn is_whole_hour(&self) -> bool {
+        self.minute() == 0 && self.is_whole_minute()
+    }
+
     fn is_between(&self, start: Time, end: Time) -> bool {
         if start <= end {
             *self >= start && *self <= end
@@ -165,6 +625,33 @@ impl ExtTime for Time {
         }
     }
 
+    fn is_in(&self, range: &TimeRange) -> bool {
+        range.contains(*self)
+    }
+
+    fn position_in(&self, start: Time, end: Time) -> Position {
+        if self.is_between(start, end) {
+            Position::Inside
+        } else if *self < start {
+            Position::Before
+        } else {
+            Position::After
+        }
+    }
+
+    fn is_in_any(&self, windows: &[(Time, Time)]) -> bool {
+        windows
+            .iter()
+            .any(|&(start, end)| self.is_between(start, end))
+    }
+
+    fn classify(&self, windows: &[(&'static str, Time, Time)]) -> Option<&'static str> {
+        windows
+            .iter()
+            .find(|&&(_, start, end)| self.is_between(start, end))
+            .map(|&(label, _, _)| label)
+    }
+
     fn add_minutes(&self, minutes: i64) -> Time {
         let total_minutes = self.hour() as i64 * 60 + self.minute() as i64 + minutes;
         let normalized_minutes = total_minutes.rem_euclid(24 * 60);
@@ -174,7 +661,7 @@ impl ExtTime for Time {
     }
 
     fn from_seconds(seconds: i64) -> Result<Time, TimeError> {
-        if seconds < 0 || seconds >= 24 * 3600 {
+        if !(0..24 * 3600).contains(&seconds) {
             return Err(TimeError::InvalidSeconds(seconds));
         }
 
@@ -186,19 +673,48 @@ impl ExtTime for Time {
             .map_err(|_| TimeError::InvalidComponents(hours, minutes))
     }
 
+    fn from_seconds_wrapping(seconds: i64) -> Time {
+        Time::from_seconds(seconds.rem_euclid(24 * 3600)).expect("Wrapped seconds are in range")
+    }
+
+    fn from_duration_since_midnight(d: Duration) -> (Time, i64) {
+        let day = Duration::DAY.whole_nanoseconds();
+        let nanos = d.whole_nanoseconds();
+        let carry = nanos.div_euclid(day) as i64;
+        let time = Time::MIDNIGHT + Duration::nanoseconds(nanos.rem_euclid(day) as i64);
+        (time, carry)
+    }
+
     fn to_seconds(&self) -> i64 {
         self.hour() as i64 * 3600 + self.minute() as i64 * 60 + self.second() as i64
     }
 
+    fn minutes_of_day(&self) -> u32 {
+        self.hour() as u32 * 60 + self.minute() as u32
+    }
+
+    fn total_seconds_of_day(&self) -> u32 {
+        self.minutes_of_day() * 60 + self.second() as u32
+    }
+
     fn align_to(&self, interval: i64) -> Result<Time, TimeError> {
         if interval == 0 {
-            return Err(TimeError::InvalidAlignmentUnit(interval.abs() as u64));
+            return Err(TimeError::InvalidAlignmentUnit(0));
         }
 
-        let total_seconds = self.to_seconds();
-        let aligned_seconds = (total_seconds / interval) * interval;
+        // Boundaries are the same for either sign; intervals past a day all floor to 00:00
+        self.align_to_floor(interval.unsigned_abs().min(86400) as i64)
+    }
+
+    fn align_to_subsec(&self, interval: i64, keep_subseconds: bool) -> Result<Time, TimeError> {
+        let aligned = self.align_to(interval)?;
+        if !keep_subseconds {
+            return Ok(aligned);
+        }
 
-        Time::from_seconds(aligned_seconds).map_err(|_| TimeError::InvalidSeconds(aligned_seconds))
+        aligned
+            .replace_nanosecond(self.nanosecond())
+            .map_err(|_| TimeError::InvalidSeconds(aligned.to_seconds()))
     }
 
     fn next_day(&self) -> Time {
@@ -233,6 +749,33 @@ impl ExtTime for Time {
         }
     }
 
+    fn prev_day(&self) -> Time {
+        // Since Time doesn't have day concept, we just return the same time
+        *self
+    }
+
+    fn prev_hour(&self) -> Time {
+        // `Time` arithmetic wraps around midnight and keeps subseconds
+        *self - Duration::hours(1)
+    }
+
+    fn prev_minute(&self) -> Time {
+        *self - Duration::minutes(1)
+    }
+
+    fn prev_second(&self) -> Time {
+        *self - Duration::seconds(1)
+    }
+
+    fn next_quarter_hour(&self) -> Time {
+        let minutes = (self.minutes_of_day() / 15 + 1) * 15 % 1440;
+        Time::from_hms((minutes / 60) as u8, (minutes % 60) as u8, 0).unwrap()
+    }
+
+    fn align_quarter_hour(&self) -> Time {
+        self.align_to(900).unwrap()
+    }
+
     fn to_hour_seconds(&self) -> i64 {
         self.hour() as i64 * 3600
     }
@@ -240,5 +783,101 @@ impl ExtTime for Time {
     fn to_minute_seconds(&self) -> i64 {
         self.hour() as i64 * 3600 + self.minute() as i64 * 60
     }
-}
 
+    fn intervals_until(&self, other: Time, interval: i64) -> Result<i64, TimeError> {
+        if interval <= 0 {
+            return Err(TimeError::InvalidInterval(interval));
+        }
+
+        Ok(other.sub_ext(*self).whole_seconds() / interval)
+    }
+
+    fn split_hms(&self) -> (u8, u8, u8) {
+        (self.hour(), self.minute(), self.second())
+    }
+
+    fn until_next_boundary(&self, interval: i64) -> Result<Duration, TimeError> {
+        if interval <= 0 {
+            return Err(TimeError::InvalidInterval(interval));
+        }
+
+        const NANOS_PER_SECOND: i64 = 1_000_000_000;
+        const NANOS_PER_DAY: i64 = 86400 * NANOS_PER_SECOND;
+        // Intervals of a day or longer only have the midnight boundary
+        let interval_nanos = interval.min(86400) * NANOS_PER_SECOND;
+        let current = self.to_seconds() * NANOS_PER_SECOND + self.nanosecond() as i64;
+        let next = ((current / interval_nanos + 1) * interval_nanos).min(NANOS_PER_DAY);
+
+        Ok(Duration::nanoseconds(next - current))
+    }
+
+    fn surrounding_boundaries(&self, interval: i64) -> Result<(Time, Time), TimeError> {
+        if interval <= 0 {
+            return Err(TimeError::InvalidInterval(interval));
+        }
+
+        const NANOS_PER_SECOND: i64 = 1_000_000_000;
+        let interval_nanos = interval.min(86400) * NANOS_PER_SECOND;
+        let current = self.to_seconds() * NANOS_PER_SECOND + self.nanosecond() as i64;
+        let floor = current / interval_nanos * interval_nanos;
+        let ceil = if floor == current {
+            floor
+        } else {
+            (floor + interval_nanos).min(86400 * NANOS_PER_SECOND)
+        };
+
+        // `Time` arithmetic wraps the end of the day to 00:00
+        Ok((
+            Time::MIDNIGHT + Duration::nanoseconds(floor),
+            Time::MIDNIGHT + Duration::nanoseconds(ceil),
+        ))
+    }
+
+    fn round_to(&self, interval: i64, mode: AlignMode) -> Result<Time, TimeError> {
+        let (floor, ceil) = self.surrounding_boundaries(interval)?;
+        Ok(match mode {
+            AlignMode::Floor => floor,
+            AlignMode::Ceil => ceil,
+            // Distances via `sub_ext` so a ceil wrapped to 00:00 still counts forward
+            AlignMode::Round if ceil.sub_ext(*self) <= self.sub_ext(floor) => ceil,
+            AlignMode::Round => floor,
+        })
+    }
+
+    fn prev_aligned(&self, interval: i64) -> Result<Time, TimeError> {
+        if interval <= 0 {
+            return Err(TimeError::InvalidInterval(interval));
+        }
+
+        const NANOS_PER_SECOND: i64 = 1_000_000_000;
+        const NANOS_PER_DAY: i64 = 86400 * NANOS_PER_SECOND;
+        let interval_nanos = interval.min(86400) * NANOS_PER_SECOND;
+        let current = self.to_seconds() * NANOS_PER_SECOND + self.nanosecond() as i64;
+        let floor = current / interval_nanos * interval_nanos;
+        let prev = if floor < current {
+            floor
+        } else if current > 0 {
+            floor - interval_nanos
+        } else {
+            // Last boundary of the previous day
+            (NANOS_PER_DAY - 1) / interval_nanos * interval_nanos
+        };
+
+        Ok(Time::MIDNIGHT + Duration::nanoseconds(prev))
+    }
+
+    fn add_seconds(&self, seconds: i64) -> Time {
+        // `Time` arithmetic wraps around midnight
+        *self + Duration::seconds(seconds.rem_euclid(24 * 3600))
+    }
+
+    fn nth_tick(&self, interval: i64, n: i64) -> Time {
+        // Reduce in i128 so large products don't overflow before wrapping
+        let offset = (interval as i128 * n as i128).rem_euclid(24 * 3600) as i64;
+        self.add_seconds(offset)
+    }
+
+    fn sub_seconds(&self, seconds: i64) -> Time {
+        *self - Duration::seconds(seconds.rem_euclid(24 * 3600))
+    }
+}
@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use time::format_description::{self, OwnedFormatItem};
+
+use crate::OffsetDateTimeError;
+
+/// Maximum number of compiled patterns kept per thread
+const CAPACITY: usize = 32;
+
+thread_local! {
+    /// Compiled patterns, least recently used first
+    static CACHE: RefCell<Vec<(String, OwnedFormatItem)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `f` with the compiled format for `pattern`, compiling and caching it on first use
+pub(crate) fn with_format<R>(
+    pattern: &str,
+    f: impl FnOnce(&OwnedFormatItem) -> R,
+) -> Result<R, OffsetDateTimeError> {
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        match cache.iter().position(|(p, _)| p == pattern) {
+            Some(index) => {
+                let entry = cache.remove(index);
+                cache.push(entry);
+            }
+            None => {
+                let item = format_description::parse_owned::<1>(pattern)
+                    .map_err(|e| OffsetDateTimeError::InvalidFormatPattern(e.to_string()))?;
+                if cache.len() == CAPACITY {
+                    cache.remove(0);
+                }
+                cache.push((pattern.to_string(), item));
+            }
+        }
+
+        let (_, item) = cache.last().expect("Entry was just inserted");
+        Ok(f(item))
+    })
+}
+
+/// Drop all compiled patterns cached on the current thread
+pub fn clear_format_cache() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+}
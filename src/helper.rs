@@ -1,6 +1,14 @@
-use time::Weekday;
+use std::collections::HashSet;
+use time::{
+    Date, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday,
+    macros::format_description as fd,
+};
 
-/// start from 0
+use crate::{OffsetDateTimeError, extend_offset_time::parse_error_detailed};
+
+/// Monday = 0 to Sunday = 6
+///
+/// Not the ISO 8601 numbering; use `weekday_to_iso` for Monday = 1 to Sunday = 7.
 pub fn weekday_to_u8(weekday: Weekday) -> u8 {
     match weekday {
         Weekday::Monday => 0,
@@ -12,3 +20,220 @@ pub fn weekday_to_u8(weekday: Weekday) -> u8 {
         Weekday::Sunday => 6,
     }
 }
+
+/// Inverse of `weekday_to_u8`, Monday = 0 to Sunday = 6
+///
+/// Returns `None` for values greater than 6.
+pub fn weekday_from_u8(n: u8) -> Option<Weekday> {
+    match n {
+        0 => Some(Weekday::Monday),
+        1 => Some(Weekday::Tuesday),
+        2 => Some(Weekday::Wednesday),
+        3 => Some(Weekday::Thursday),
+        4 => Some(Weekday::Friday),
+        5 => Some(Weekday::Saturday),
+        6 => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+/// ISO 8601 weekday number, Monday = 1 to Sunday = 7
+pub fn weekday_to_iso(weekday: Weekday) -> u8 {
+    weekday_to_u8(weekday) + 1
+}
+
+/// Inverse of `weekday_to_iso`, Monday = 1 to Sunday = 7
+///
+/// Returns `None` for 0 and values greater than 7.
+pub fn weekday_from_iso(n: u8) -> Option<Weekday> {
+    n.checked_sub(1).and_then(weekday_from_u8)
+}
+
+/// Sunday-first weekday number as used by JavaScript's `Date.getDay()`, Sunday = 0 to Saturday = 6
+pub fn weekday_to_sunday0(weekday: Weekday) -> u8 {
+    (weekday_to_u8(weekday) + 1) % 7
+}
+
+/// Inverse of `weekday_to_sunday0`, Sunday = 0 to Saturday = 6
+///
+/// Returns `None` for values greater than 6.
+pub fn weekday_from_sunday0(n: u8) -> Option<Weekday> {
+    if n > 6 {
+        return None;
+    }
+    weekday_from_u8((n + 6) % 7)
+}
+
+/// Convert offset hours to total offset seconds
+pub fn offset_hours_to_seconds(hours: i8) -> i32 {
+    hours as i32 * 3600
+}
+
+/// Build a `UtcOffset` from total offset seconds (may include minutes and seconds)
+pub fn offset_seconds_to_utcoffset(seconds: i32) -> Result<UtcOffset, OffsetDateTimeError> {
+    UtcOffset::from_whole_seconds(seconds)
+        .map_err(|_| OffsetDateTimeError::InvalidOffsetSeconds(seconds))
+}
+
+/// Build a whole-hour `UtcOffset`, e.g. 8 for +08:00
+pub(crate) fn offset_from_hours(offset_hours: i8) -> Result<UtcOffset, OffsetDateTimeError> {
+    UtcOffset::from_hms(offset_hours, 0, 0)
+        .map_err(|_| OffsetDateTimeError::InvalidOffsetHours(offset_hours))
+}
+
+/// Build a `UtcOffset` from hours, minutes and seconds, recording all three on failure
+pub(crate) fn offset_from_hms(
+    hours: i8,
+    minutes: i8,
+    seconds: i8,
+) -> Result<UtcOffset, OffsetDateTimeError> {
+    UtcOffset::from_hms(hours, minutes, seconds)
+        .map_err(|_| OffsetDateTimeError::InvalidOffsetHms(hours, minutes, seconds))
+}
+
+/// Check whether the offset has a non-zero minutes or seconds component, e.g. +05:30
+pub fn offset_has_minutes(offset: UtcOffset) -> bool {
+    offset.whole_seconds() % 3600 != 0
+}
+
+/// Convert an offset to whole hours for the `offset_hours: i8` methods
+///
+/// # Returns
+/// * `Ok(i8)` - Offset in hours
+/// * `Err` - If the offset has minutes or seconds, which would shift the resulting instant
+pub fn offset_to_whole_hours(offset: UtcOffset) -> Result<i8, OffsetDateTimeError> {
+    if offset_has_minutes(offset) {
+        return Err(OffsetDateTimeError::OffsetHasMinutes(
+            offset.whole_seconds(),
+        ));
+    }
+
+    Ok(offset.whole_hours())
+}
+
+/// Format offset as a canonical string with mandatory sign, e.g. "+08:00" or "-05:30"
+///
+/// Seconds past the minute are not included.
+pub fn offset_to_string(offset: UtcOffset) -> String {
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    format!(
+        "{}{:02}:{:02}",
+        sign,
+        offset.whole_hours().unsigned_abs(),
+        offset.minutes_past_hour().unsigned_abs()
+    )
+}
+
+/// Parse a list of YYYYMMDD strings into a set of holiday dates
+pub fn parse_holidays(dates: &[&str]) -> Result<HashSet<Date>, OffsetDateTimeError> {
+    let format = fd!("[year][month][day]");
+    dates
+        .iter()
+        .map(|input| {
+            Date::parse(input, &format).map_err(|e| parse_error_detailed(input, format, e))
+        })
+        .collect()
+}
+
+/// Parse a compact duration string such as "1h30m", "90m", "45s" or "2d"
+///
+/// Supported units are `d`, `h`, `m` and `s`; components are summed.
+pub fn parse_duration(s: &str) -> Result<Duration, OffsetDateTimeError> {
+    let invalid = || OffsetDateTimeError::InvalidDuration(s.to_string());
+
+    let mut total = Duration::ZERO;
+    let mut components = 0;
+    let mut digits = String::new();
+    for c in s.trim().chars() {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+
+        let value: i64 = digits.parse().map_err(|_| invalid())?;
+        digits.clear();
+        let unit_seconds = match c {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(invalid()),
+        };
+        let component = value
+            .checked_mul(unit_seconds)
+            .map(Duration::seconds)
+            .ok_or_else(invalid)?;
+        total = total.checked_add(component).ok_or_else(invalid)?;
+        components += 1;
+    }
+
+    // Reject empty input and trailing numbers without a unit
+    if components == 0 || !digits.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(total)
+}
+
+/// Earliest datetime in the slice, compared by instant regardless of offset
+pub fn min_datetime(dts: &[OffsetDateTime]) -> Option<OffsetDateTime> {
+    dts.iter().min().copied()
+}
+
+/// Latest datetime in the slice, compared by instant regardless of offset
+pub fn max_datetime(dts: &[OffsetDateTime]) -> Option<OffsetDateTime> {
+    dts.iter().max().copied()
+}
+
+/// Circular mean of clock times on the 24h circle, rounded to the nearest millisecond
+///
+/// Each time is mapped to a unit vector by its angle on the clock face and the vectors are
+/// summed, so 23:00 and 01:00 average to 00:00 rather than 12:00.
+///
+/// # Returns
+/// * `Some(Time)` - Mean time
+/// * `None` - If the slice is empty or the vectors cancel out (e.g. 06:00 and 18:00)
+pub fn mean_time(times: &[Time]) -> Option<Time> {
+    const DAY_MILLIS: f64 = 86_400_000.0;
+
+    let (sin, cos) = times.iter().fold((0.0_f64, 0.0_f64), |(sin, cos), t| {
+        let (h, m, s, nanos) = t.as_hms_nano();
+        let seconds =
+            h as f64 * 3600.0 + m as f64 * 60.0 + s as f64 + nanos as f64 / 1_000_000_000.0;
+        let angle = seconds / 86_400.0 * std::f64::consts::TAU;
+        (sin + angle.sin(), cos + angle.cos())
+    });
+
+    if sin.hypot(cos) < 1e-9 * times.len().max(1) as f64 {
+        return None;
+    }
+
+    let millis = (sin.atan2(cos) / std::f64::consts::TAU * DAY_MILLIS).round() as i64;
+    let millis = millis.rem_euclid(DAY_MILLIS as i64);
+    Some(Time::MIDNIGHT + Duration::milliseconds(millis))
+}
+
+/// Number of days in the given month, accounting for leap years
+pub(crate) fn days_in_month(year: i32, month: Month) -> u8 {
+    match month {
+        Month::January
+        | Month::March
+        | Month::May
+        | Month::July
+        | Month::August
+        | Month::October
+        | Month::December => 31,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        Month::February if time::util::is_leap_year(year) => 29,
+        Month::February => 28,
+    }
+}
+
+/// Add calendar months to a date, clamping the day to the last valid day of the target month
+pub(crate) fn add_months_clamped(date: Date, months: i64) -> Option<Date> {
+    let total = date.year() as i64 * 12 + (date.month() as i64 - 1) + months;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+    let month = Month::try_from(total.rem_euclid(12) as u8 + 1).ok()?;
+    let day = date.day().min(days_in_month(year, month));
+    Date::from_calendar_date(year, month, day).ok()
+}
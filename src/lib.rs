@@ -1,10 +1,36 @@
+mod date_session;
+mod date_time_range;
+mod extend_date;
+mod extend_duration;
 mod extend_offset_time;
 mod extend_serde;
 mod extend_time;
+mod format_cache;
 mod helper;
+mod monotonic_clock;
+mod time_range;
+mod weekly_schedule;
 
-pub use extend_offset_time::{ExtOffsetDateTime, OffsetDateTimeError};
-pub use extend_serde::{serde_parse_ts, serde_t2ts};
-pub use extend_time::{ExtTime, TimeError};
-pub use helper::weekday_to_u8;
+pub use date_session::DateSession;
+pub use date_time_range::DateTimeRange;
+pub use extend_date::{DateError, ExtDate};
+pub use extend_duration::ExtDuration;
+pub use extend_offset_time::{ExtOffsetDateTime, OffsetDateTimeError, ParseFailure};
+pub use extend_serde::{
+    serde_parse_rfc3339, serde_parse_ts, serde_parse_ts_and_str, serde_parse_ts_millis,
+    serde_parse_ts_nanos, serde_parse_ts_nanos_str, serde_parse_ts_opt, serde_parse_ts_pair,
+    serde_t2rfc3339, serde_t2ts, serde_t2ts_and_str, serde_t2ts_millis, serde_t2ts_nanos,
+    serde_t2ts_nanos_str, serde_t2ts_opt, serde_t2ts_pair,
+};
+pub use extend_time::{AlignMode, ExtTime, Position, SubUnit, TimeError};
+pub use format_cache::clear_format_cache;
+pub use helper::{
+    max_datetime, mean_time, min_datetime, offset_has_minutes, offset_hours_to_seconds,
+    offset_seconds_to_utcoffset, offset_to_string, offset_to_whole_hours, parse_duration,
+    parse_holidays, weekday_from_iso, weekday_from_sunday0, weekday_from_u8, weekday_to_iso,
+    weekday_to_sunday0, weekday_to_u8,
+};
+pub use monotonic_clock::MonotonicClock;
 pub use time::{OffsetDateTime, Time, macros};
+pub use time_range::TimeRange;
+pub use weekly_schedule::WeeklySchedule;
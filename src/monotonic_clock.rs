@@ -0,0 +1,33 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::{ExtOffsetDateTime, OffsetDateTimeError};
+
+/// Clock producing strictly increasing datetimes, e.g. for ordered event IDs
+///
+/// If the system clock has not advanced (or went backwards) since the last
+/// call, the previous value is bumped by one nanosecond instead.
+#[derive(Debug, Clone, Default)]
+pub struct MonotonicClock {
+    last: Option<OffsetDateTime>,
+}
+
+impl MonotonicClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the current time with timezone offset (hours from UTC), strictly after the last returned value
+    ///
+    /// # Returns
+    /// * `Ok(OffsetDateTime)` - Current time, strictly after the last returned value
+    /// * `Err` - If offset is invalid; the clock state is left unchanged
+    pub fn next(&mut self, offset_hours: i8) -> Result<OffsetDateTime, OffsetDateTimeError> {
+        let now = OffsetDateTime::try_now_with_offset(offset_hours)?;
+        let next = match self.last {
+            Some(last) if now <= last => (last + Duration::nanoseconds(1)).to_offset(now.offset()),
+            _ => now,
+        };
+        self.last = Some(next);
+        Ok(next)
+    }
+}
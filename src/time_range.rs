@@ -0,0 +1,23 @@
+use time::Time;
+
+use crate::ExtTime;
+
+/// A daily window of wall-clock time from `start` to `end` (inclusive)
+///
+/// Windows with `end` before `start` cross midnight, e.g. 21:00 to 02:30.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: Time,
+    pub end: Time,
+}
+
+impl TimeRange {
+    pub fn new(start: Time, end: Time) -> Self {
+        Self { start, end }
+    }
+
+    /// Check if the time is inside the window, handling cross-day windows
+    pub fn contains(&self, t: Time) -> bool {
+        t.is_between(self.start, self.end)
+    }
+}
@@ -0,0 +1,29 @@
+use time::{Duration, OffsetDateTime, Time, Weekday};
+
+/// A recurring schedule firing at a fixed time of day on selected weekdays
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeeklySchedule {
+    pub days: Vec<Weekday>,
+    pub at: Time,
+}
+
+impl WeeklySchedule {
+    pub fn new(days: Vec<Weekday>, at: Time) -> Self {
+        Self { days, at }
+    }
+
+    /// Get the soonest fire time at or after `from`
+    ///
+    /// Weekdays and `at` are taken in `from`'s offset, and the result keeps that offset.
+    ///
+    /// # Returns
+    /// * `Some(OffsetDateTime)` - Next datetime on one of `days` at `at`
+    /// * `None` - If `days` is empty, or the next fire time is past `OffsetDateTime`'s range
+    pub fn next_fire(&self, from: OffsetDateTime) -> Option<OffsetDateTime> {
+        // Eight days covers a match on today's weekday whose time has already passed
+        (0..=7)
+            .map_while(|offset| from.checked_add(Duration::days(offset)))
+            .map(|day| day.replace_time(self.at))
+            .find(|candidate| *candidate >= from && self.days.contains(&candidate.weekday()))
+    }
+}
@@ -0,0 +1,26 @@
+use ext_time::DateSession;
+use time::macros::{date, datetime, time};
+
+#[test]
+fn test_to_datetimes() {
+    let session = DateSession::new(date!(2024 - 03 - 15), time!(9:30), time!(16:00));
+    let (open, close) = session.to_datetimes(8).unwrap();
+    assert_eq!(open, datetime!(2024-03-15 09:30 +08:00));
+    assert_eq!(close, datetime!(2024-03-15 16:00 +08:00));
+    assert_eq!(open.offset().whole_hours(), 8);
+
+    assert!(session.to_datetimes(30).is_err());
+}
+
+#[test]
+fn test_to_datetimes_overnight() {
+    let session = DateSession::new(date!(2024 - 03 - 15), time!(21:00), time!(2:30));
+    let (open, close) = session.to_datetimes(8).unwrap();
+    assert_eq!(open, datetime!(2024-03-15 21:00 +08:00));
+    assert_eq!(close, datetime!(2024-03-16 02:30 +08:00));
+
+    // Month boundary
+    let session = DateSession::new(date!(2024 - 02 - 29), time!(22:00), time!(0:00));
+    let (_, close) = session.to_datetimes(0).unwrap();
+    assert_eq!(close, datetime!(2024-03-01 00:00 UTC));
+}
@@ -0,0 +1,46 @@
+use ext_time::DateTimeRange;
+use time::{Duration, macros::datetime};
+
+#[test]
+fn test_split() {
+    let range = DateTimeRange::new(
+        datetime!(2024-03-15 09:00 +08:00),
+        datetime!(2024-03-15 10:40 +08:00),
+    );
+    let windows = range.split(Duration::minutes(30));
+    assert_eq!(
+        windows,
+        vec![
+            (
+                datetime!(2024-03-15 09:00 +08:00),
+                datetime!(2024-03-15 09:30 +08:00)
+            ),
+            (
+                datetime!(2024-03-15 09:30 +08:00),
+                datetime!(2024-03-15 10:00 +08:00)
+            ),
+            (
+                datetime!(2024-03-15 10:00 +08:00),
+                datetime!(2024-03-15 10:30 +08:00)
+            ),
+            (
+                datetime!(2024-03-15 10:30 +08:00),
+                datetime!(2024-03-15 10:40 +08:00)
+            ),
+        ]
+    );
+
+    // Exact multiple has no short tail
+    assert_eq!(range.split(Duration::minutes(50)).len(), 2);
+}
+
+#[test]
+fn test_split_empty() {
+    let start = datetime!(2024-03-15 09:00 +08:00);
+    let range = DateTimeRange::new(start, start);
+    assert!(range.split(Duration::minutes(30)).is_empty());
+
+    let range = DateTimeRange::new(start, start + Duration::hours(1));
+    assert!(range.split(Duration::ZERO).is_empty());
+    assert!(range.split(Duration::minutes(-5)).is_empty());
+}
@@ -0,0 +1,100 @@
+use ext_time::ExtDate;
+use time::{Date, Weekday, macros::date};
+
+#[test]
+fn test_next_day_checked() {
+    assert_eq!(
+        date!(2024 - 02 - 28).next_day_checked().unwrap(),
+        date!(2024 - 02 - 29)
+    );
+    assert_eq!(
+        date!(2024 - 12 - 31).next_day_checked().unwrap(),
+        date!(2025 - 01 - 01)
+    );
+    assert!(Date::MAX.next_day_checked().is_err());
+}
+
+#[test]
+fn test_next_month() {
+    assert_eq!(
+        date!(2024 - 01 - 31).next_month().unwrap(),
+        date!(2024 - 02 - 29)
+    );
+    assert_eq!(
+        date!(2023 - 01 - 31).next_month().unwrap(),
+        date!(2023 - 02 - 28)
+    );
+    assert_eq!(
+        date!(2024 - 03 - 15).next_month().unwrap(),
+        date!(2024 - 04 - 15)
+    );
+    // Year rollover
+    assert_eq!(
+        date!(2024 - 12 - 15).next_month().unwrap(),
+        date!(2025 - 01 - 15)
+    );
+    assert!(Date::MAX.next_month().is_err());
+}
+
+#[test]
+fn test_next_year() {
+    assert_eq!(
+        date!(2024 - 03 - 15).next_year().unwrap(),
+        date!(2025 - 03 - 15)
+    );
+    assert_eq!(
+        date!(2024 - 02 - 29).next_year().unwrap(),
+        date!(2025 - 02 - 28)
+    );
+    assert!(Date::MAX.next_year().is_err());
+}
+
+#[test]
+fn test_week_bounds() {
+    let d = date!(2024 - 03 - 15); // Friday
+    assert_eq!(
+        d.start_of_week(Weekday::Monday).unwrap(),
+        date!(2024 - 03 - 11)
+    );
+    assert_eq!(
+        d.end_of_week(Weekday::Monday).unwrap(),
+        date!(2024 - 03 - 17)
+    );
+    assert_eq!(
+        d.start_of_week(Weekday::Sunday).unwrap(),
+        date!(2024 - 03 - 10)
+    );
+    assert_eq!(
+        d.end_of_week(Weekday::Sunday).unwrap(),
+        date!(2024 - 03 - 16)
+    );
+
+    // First day of the week is its own start
+    let monday = date!(2024 - 03 - 11);
+    assert_eq!(monday.start_of_week(Weekday::Monday).unwrap(), monday);
+
+    // Across a year boundary
+    assert_eq!(
+        date!(2025 - 01 - 01)
+            .start_of_week(Weekday::Monday)
+            .unwrap(),
+        date!(2024 - 12 - 30)
+    );
+}
+
+#[test]
+fn test_month_bounds() {
+    let d = date!(2024 - 02 - 15);
+    assert_eq!(d.start_of_month(), date!(2024 - 02 - 01));
+    assert_eq!(d.end_of_month(), date!(2024 - 02 - 29));
+    assert_eq!(date!(2023 - 02 - 15).end_of_month(), date!(2023 - 02 - 28));
+    assert_eq!(date!(2024 - 12 - 01).end_of_month(), date!(2024 - 12 - 31));
+}
+
+#[test]
+fn test_is_weekend() {
+    assert!(!date!(2024 - 03 - 15).is_weekend());
+    assert!(date!(2024 - 03 - 16).is_weekend());
+    assert!(date!(2024 - 03 - 17).is_weekend());
+    assert!(!date!(2024 - 03 - 18).is_weekend());
+}
@@ -0,0 +1,23 @@
+use ext_time::ExtDuration;
+use time::Duration;
+
+#[test]
+fn test_to_chinese_string() {
+    assert_eq!(Duration::minutes(90).to_chinese_string(), "1小时30分");
+    assert_eq!(Duration::seconds(45).to_chinese_string(), "45秒");
+    assert_eq!(Duration::seconds(3605).to_chinese_string(), "1小时5秒");
+    assert_eq!(Duration::hours(2).to_chinese_string(), "2小时");
+    assert_eq!(Duration::ZERO.to_chinese_string(), "0秒");
+
+    // Days roll into hours
+    assert_eq!(
+        (Duration::days(1) + Duration::hours(2) + Duration::minutes(3)).to_chinese_string(),
+        "26小时3分"
+    );
+
+    // Subseconds are truncated
+    assert_eq!(Duration::milliseconds(61_500).to_chinese_string(), "1分1秒");
+    assert_eq!(Duration::milliseconds(500).to_chinese_string(), "0秒");
+
+    assert_eq!(Duration::minutes(-90).to_chinese_string(), "-1小时30分");
+}
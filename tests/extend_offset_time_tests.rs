@@ -34,6 +34,157 @@ fn test_to_display_string_with_offset() {
     assert_eq!(str_utc_minus8, "2024-03-14 20:00:00-08:00");
 }
 
+#[test]
+fn test_try_to_display_string() {
+    let dt = create_test_datetime();
+    assert_eq!(
+        dt.try_to_display_string(8).unwrap(),
+        "2024-03-15 14:30:45+08:00"
+    );
+    assert_eq!(
+        dt.try_to_display_string(-8).unwrap(),
+        "2024-03-14 22:30:45-08:00"
+    );
+    assert_eq!(
+        dt.try_to_display_string(0).unwrap(),
+        dt.to_display_string(0)
+    );
+
+    assert!(matches!(
+        dt.try_to_display_string(30),
+        Err(ext_time::OffsetDateTimeError::InvalidOffsetHours(30))
+    ));
+}
+
+#[test]
+fn test_to_naive_string() {
+    let dt = create_test_datetime();
+    assert_eq!(dt.to_naive_string(8).unwrap(), "2024-03-15 14:30:45");
+    assert_eq!(dt.to_naive_string(0).unwrap(), "2024-03-15 06:30:45");
+    assert_eq!(dt.to_naive_string(-8).unwrap(), "2024-03-14 22:30:45");
+    assert!(dt.to_naive_string(30).is_err());
+}
+
+#[test]
+fn test_to_display_string_hms() {
+    let dt = create_test_datetime(); // 06:30:45 UTC
+    assert_eq!(
+        dt.to_display_string_hms(5, 30, 0).unwrap(),
+        "2024-03-15 12:00:45+05:30"
+    );
+    assert_eq!(
+        dt.to_display_string_hms(5, 45, 0).unwrap(),
+        "2024-03-15 12:15:45+05:45"
+    );
+    assert_eq!(
+        dt.to_display_string_hms(-3, -30, 0).unwrap(),
+        "2024-03-15 03:00:45-03:30"
+    );
+    assert_eq!(
+        dt.to_display_string_hms(8, 0, 0).unwrap(),
+        dt.to_display_string(8)
+    );
+
+    assert!(dt.to_display_string_hms(30, 0, 0).is_err());
+    assert!(dt.to_display_string_hms(5, 60, 0).is_err());
+    // Offset seconds cannot be shown, so they are rejected rather than dropped
+    assert!(dt.to_display_string_hms(5, 30, 45).is_err());
+}
+
+#[test]
+fn test_now_with_offset_hms() {
+    let dt = OffsetDateTime::now_with_offset_hms(5, 30, 0).unwrap();
+    assert_eq!(dt.offset(), UtcOffset::from_hms(5, 30, 0).unwrap());
+
+    let err = OffsetDateTime::now_with_offset_hms(5, 75, 0).unwrap_err();
+    assert_eq!(err.to_string(), "Invalid offset: 5h 75m 0s");
+}
+
+#[test]
+fn test_try_now_with_offset() {
+    let dt = OffsetDateTime::try_now_with_offset(-5).unwrap();
+    assert_eq!(dt.offset(), UtcOffset::from_hms(-5, 0, 0).unwrap());
+
+    assert!(OffsetDateTime::try_now_with_offset(30).is_err());
+    assert!(OffsetDateTime::from_date_and_seconds(dt.date(), 0, 30).is_err());
+}
+
+#[test]
+fn test_to_display_with_label() {
+    let dt = create_test_datetime();
+    assert_eq!(
+        dt.to_display_with_label(8, "CST").unwrap(),
+        "2024-03-15 14:30:45+08:00 CST"
+    );
+    assert_eq!(
+        dt.to_display_with_label(-5, "EST").unwrap(),
+        "2024-03-15 01:30:45-05:00 EST"
+    );
+    assert!(dt.to_display_with_label(30, "CST").is_err());
+}
+
+#[test]
+fn test_to_display_strings() {
+    let dt = create_test_datetime();
+    let dts = [dt, dt.next_hour(), dt.to_offset(UtcOffset::UTC)];
+
+    let strings = OffsetDateTime::to_display_strings(&dts, 8).unwrap();
+    assert_eq!(
+        strings,
+        vec![
+            "2024-03-15 14:30:45+08:00",
+            "2024-03-15 15:30:45+08:00",
+            "2024-03-15 14:30:45+08:00",
+        ]
+    );
+    for (s, dt) in strings.iter().zip(dts.iter()) {
+        assert_eq!(*s, dt.to_display_string(8));
+    }
+
+    assert!(
+        OffsetDateTime::to_display_strings(&[], 8)
+            .unwrap()
+            .is_empty()
+    );
+    assert!(OffsetDateTime::to_display_strings(&dts, 30).is_err());
+}
+
+#[test]
+fn test_to_offset_hours_checked() {
+    let dt = create_test_datetime();
+    let utc = dt.to_offset_hours_checked(0).unwrap();
+    assert_eq!(utc, dt);
+    assert_eq!(utc.offset(), UtcOffset::UTC);
+    assert_eq!(utc.hour(), 6);
+
+    let ny = dt.to_offset_hours_checked(-5).unwrap();
+    assert_eq!(ny, dt);
+    assert_eq!(ny.offset().whole_hours(), -5);
+
+    assert!(matches!(
+        dt.to_offset_hours_checked(30),
+        Err(ext_time::OffsetDateTimeError::InvalidOffsetHours(30))
+    ));
+}
+
+#[test]
+fn test_time_at_offset() {
+    let dt = create_test_datetime(); // 14:30:45 +08:00
+    assert_eq!(
+        dt.time_at_offset(8).unwrap(),
+        Time::from_hms(14, 30, 45).unwrap()
+    );
+    assert_eq!(
+        dt.time_at_offset(9).unwrap(),
+        Time::from_hms(15, 30, 45).unwrap()
+    );
+    assert_eq!(
+        dt.time_at_offset(-5).unwrap(),
+        Time::from_hms(1, 30, 45).unwrap()
+    );
+    assert!(dt.time_at_offset(30).is_err());
+}
+
 #[test]
 fn test_to_chinese_string() {
     let time_with_offset = OffsetDateTime::now_utc()
@@ -47,6 +198,249 @@ fn test_to_chinese_string() {
     assert_eq!(chinese_str, "2024年03月15日 12时00分00秒 +08:00");
 }
 
+#[test]
+fn test_timestamp_rounded() {
+    let dt = OffsetDateTime::from_unix_timestamp(1_710_484_245).unwrap();
+    assert_eq!(dt.timestamp_rounded(60).unwrap(), 1_710_484_260);
+    assert_eq!(dt.timestamp_rounded(10).unwrap(), 1_710_484_250); // half-up
+    assert_eq!(dt.timestamp_rounded(3600).unwrap(), 1_710_486_000);
+    assert_eq!(dt.timestamp_rounded(1).unwrap(), 1_710_484_245);
+
+    // Pre-epoch
+    let dt = OffsetDateTime::from_unix_timestamp(-75).unwrap();
+    assert_eq!(dt.timestamp_rounded(60).unwrap(), -60);
+    let dt = OffsetDateTime::from_unix_timestamp(-90).unwrap();
+    assert_eq!(dt.timestamp_rounded(60).unwrap(), -60);
+
+    assert!(dt.timestamp_rounded(0).is_err());
+    assert!(dt.timestamp_rounded(-60).is_err());
+}
+
+#[test]
+fn test_milli_timestamp_rounded() {
+    let dt = OffsetDateTime::from_unix_timestamp(1_710_484_245)
+        .unwrap()
+        .replace_millisecond(149)
+        .unwrap();
+    assert_eq!(dt.milli_timestamp_rounded(100), 1_710_484_245_100);
+    assert_eq!(dt.milli_timestamp_rounded(1000), 1_710_484_245_000);
+
+    let dt = dt.replace_millisecond(150).unwrap();
+    assert_eq!(dt.milli_timestamp_rounded(100), 1_710_484_245_200); // half-up
+    assert_eq!(dt.milli_timestamp_rounded(1), 1_710_484_245_150);
+
+    // Pre-epoch
+    let dt = OffsetDateTime::UNIX_EPOCH - time::Duration::milliseconds(149);
+    assert_eq!(dt.milli_timestamp_rounded(100), -100);
+
+    // Non-positive units leave the value untouched
+    assert_eq!(dt.milli_timestamp_rounded(0), dt.milli_timestamp());
+    assert_eq!(dt.milli_timestamp_rounded(-100), dt.milli_timestamp());
+}
+
+#[test]
+fn test_duration_since_epoch() {
+    let dt = create_test_datetime()
+        .replace_nanosecond(123_456_789)
+        .unwrap();
+    let d = dt.duration_since_epoch();
+    assert_eq!(d.whole_seconds(), dt.unix_timestamp());
+    assert_eq!(d.subsec_nanoseconds(), 123_456_789);
+    assert_eq!(d, dt - OffsetDateTime::UNIX_EPOCH);
+
+    // Pre-epoch
+    let dt = OffsetDateTime::UNIX_EPOCH - time::Duration::milliseconds(1500);
+    assert_eq!(
+        dt.duration_since_epoch(),
+        time::Duration::milliseconds(-1500)
+    );
+}
+
+#[test]
+fn test_truncate_subseconds() {
+    let dt = create_test_datetime();
+    let with_millis = dt.replace_nanosecond(123_456_789).unwrap();
+    assert_eq!(with_millis.truncate_subseconds(), dt);
+    assert_eq!(dt.truncate_subseconds(), dt);
+
+    let from_millis =
+        OffsetDateTime::from_milliseconds(with_millis.milli_timestamp() as u64, 8).unwrap();
+    assert_eq!(from_millis.truncate_subseconds(), dt);
+}
+
+#[test]
+fn test_ceil_to_units() {
+    let dt = create_test_datetime(); // 14:30:45
+    assert_eq!(dt.ceil_to_second(), dt);
+    assert_eq!(
+        dt.ceil_to_minute().time(),
+        Time::from_hms(14, 31, 0).unwrap()
+    );
+    assert_eq!(dt.ceil_to_hour().time(), Time::from_hms(15, 0, 0).unwrap());
+
+    let dt = dt.replace_millisecond(1).unwrap();
+    assert_eq!(
+        dt.ceil_to_second().time(),
+        Time::from_hms(14, 30, 46).unwrap()
+    );
+
+    // Already aligned values are returned unchanged
+    let dt = create_test_datetime().replace_time(Time::from_hms(14, 0, 0).unwrap());
+    assert_eq!(dt.ceil_to_minute(), dt);
+    assert_eq!(dt.ceil_to_hour(), dt);
+
+    // Crosses into the next day
+    let dt = create_test_datetime().replace_time(Time::from_hms_milli(23, 59, 59, 500).unwrap());
+    let ceiled = dt.ceil_to_hour();
+    assert_eq!(ceiled.day(), 16);
+    assert_eq!(ceiled.time(), Time::MIDNIGHT);
+    assert_eq!(dt.ceil_to_minute(), ceiled);
+    assert_eq!(dt.ceil_to_second(), ceiled);
+    assert_eq!(ceiled.offset(), dt.offset());
+}
+
+#[test]
+fn test_sort_key() {
+    let dt = create_test_datetime().replace_nanosecond(5).unwrap();
+    let key = dt.to_sort_key().unwrap();
+    assert_eq!(key, "20240315063045000000005");
+    assert_eq!(key.len(), 23);
+
+    let back = OffsetDateTime::from_sort_key(&key).unwrap();
+    assert_eq!(back, dt);
+    assert_eq!(back.offset(), UtcOffset::UTC);
+
+    // String order equals time order
+    let mut dts = [
+        dt.next_day(),
+        dt,
+        dt.next_second(),
+        dt.replace_nanosecond(4).unwrap(),
+    ];
+    let mut keys: Vec<String> = dts.iter().map(|d| d.to_sort_key().unwrap()).collect();
+    dts.sort();
+    keys.sort();
+    assert_eq!(
+        keys,
+        dts.iter()
+            .map(|d| d.to_sort_key().unwrap())
+            .collect::<Vec<_>>()
+    );
+
+    assert!(OffsetDateTime::from_sort_key("2024031506304500000000").is_err());
+    assert!(OffsetDateTime::from_sort_key("20241315063045000000005").is_err());
+
+    // Years outside 0..=9999 would not sort as strings
+    let year_zero = dt.replace_date(Date::from_calendar_date(0, time::Month::March, 15).unwrap());
+    assert!(year_zero.to_sort_key().unwrap() < key);
+    let negative = dt.replace_date(Date::from_calendar_date(-1, time::Month::March, 15).unwrap());
+    assert!(matches!(
+        negative.to_sort_key(),
+        Err(ext_time::OffsetDateTimeError::InvalidSortKeyYear(-1))
+    ));
+}
+
+#[test]
+fn test_to_rfc3339_z() {
+    let dt = create_test_datetime();
+    assert_eq!(dt.to_rfc3339_z().unwrap(), "2024-03-15T06:30:45Z");
+
+    let dt = dt.replace_millisecond(120).unwrap();
+    assert_eq!(dt.to_rfc3339_z().unwrap(), "2024-03-15T06:30:45.12Z");
+}
+
+#[test]
+fn test_try_parse_any() {
+    use ext_time::ParseFailure;
+
+    let expected = create_test_datetime();
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024-03-15T14:30:45+08:00").unwrap(),
+        expected
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024-03-15T06:30:45Z").unwrap(),
+        expected
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any(" 2024-03-15 14:30:45+08:00 ").unwrap(),
+        expected
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024-03-15T14:30:45.250+08:00")
+            .unwrap()
+            .millisecond(),
+        250
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any(&expected.unix_timestamp().to_string()).unwrap(),
+        expected
+    );
+
+    assert_eq!(OffsetDateTime::try_parse_any(""), Err(ParseFailure::Empty));
+    assert_eq!(
+        OffsetDateTime::try_parse_any("   "),
+        Err(ParseFailure::Empty)
+    );
+
+    assert_eq!(
+        OffsetDateTime::try_parse_any("yesterday"),
+        Err(ParseFailure::UnknownFormat)
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024/03/15 14:30:45"),
+        Err(ParseFailure::UnknownFormat)
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024-03-15T14:30:45."),
+        Err(ParseFailure::UnknownFormat)
+    );
+
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024-13-15T14:30:45Z"),
+        Err(ParseFailure::OutOfRange)
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any("2024-02-30 14:30:45+08:00"),
+        Err(ParseFailure::OutOfRange)
+    );
+    assert_eq!(
+        OffsetDateTime::try_parse_any("99999999999999999999"),
+        Err(ParseFailure::OutOfRange)
+    );
+}
+
+#[test]
+fn test_from_milliseconds_parts() {
+    let expected = create_test_datetime().replace_millisecond(250).unwrap();
+    let (dt, offset) =
+        OffsetDateTime::from_milliseconds_parts(expected.milli_timestamp() as u64, 8).unwrap();
+    assert_eq!(dt.offset(), UtcOffset::UTC);
+    assert_eq!(offset.whole_hours(), 8);
+    assert_eq!(dt, expected);
+    assert_eq!(dt.to_offset(offset).hour(), 14);
+
+    assert!(OffsetDateTime::from_milliseconds_parts(0, 30).is_err());
+}
+
+#[test]
+fn test_to_chinese_string_millis() {
+    let dt = create_test_datetime().replace_millisecond(123).unwrap();
+    assert_eq!(
+        dt.to_chinese_string_millis(8).unwrap(),
+        "2024年03月15日 14时30分45.123秒 +08:00"
+    );
+    assert_eq!(
+        dt.to_chinese_string_millis(0).unwrap(),
+        "2024年03月15日 06时30分45.123秒 +00:00"
+    );
+    assert_eq!(
+        create_test_datetime().to_chinese_string_millis(8).unwrap(),
+        "2024年03月15日 14时30分45.000秒 +08:00"
+    );
+    assert!(dt.to_chinese_string_millis(30).is_err());
+}
+
 #[test]
 fn test_replace_time_with_seconds() {
     let dt = create_test_datetime();
@@ -68,6 +462,83 @@ fn test_replace_time_with_seconds() {
     assert!(dt.replace_time_with_seconds(24 * 3600).is_err());
 }
 
+#[test]
+fn test_today_at_seconds() {
+    let dt = OffsetDateTime::today_at_seconds(9 * 3600 + 30 * 60, 8).unwrap();
+    assert_eq!(dt.time(), Time::from_hms(9, 30, 0).unwrap());
+    assert_eq!(dt.offset().whole_hours(), 8);
+    assert_eq!(dt.date(), OffsetDateTime::now_with_offset(8).date());
+
+    assert!(OffsetDateTime::today_at_seconds(24 * 3600, 8).is_err());
+    assert!(OffsetDateTime::today_at_seconds(0, 30).is_err());
+}
+
+#[test]
+fn test_from_date_and_seconds() {
+    let date = Date::from_calendar_date(2024, time::Month::March, 15).unwrap();
+    let dt = OffsetDateTime::from_date_and_seconds(date, 14 * 3600 + 30 * 60 + 45, 8).unwrap();
+    assert_eq!(dt, create_test_datetime());
+    assert_eq!(dt.offset().whole_hours(), 8);
+
+    let dt = OffsetDateTime::from_date_and_seconds(date, 86_399, -5).unwrap();
+    assert_eq!(dt.date(), date);
+    assert_eq!(dt.time(), Time::from_hms(23, 59, 59).unwrap());
+
+    assert!(OffsetDateTime::from_date_and_seconds(date, 86_400, 8).is_err());
+    assert!(OffsetDateTime::from_date_and_seconds(date, -1, 8).is_err());
+    assert!(OffsetDateTime::from_date_and_seconds(date, 0, 30).is_err());
+}
+
+#[test]
+fn test_replace_time_with_millis() {
+    let dt = create_test_datetime();
+
+    let new_dt = dt.replace_time_with_millis(37_230_250).unwrap();
+    assert_eq!(
+        new_dt.time(),
+        Time::from_hms_milli(10, 20, 30, 250).unwrap()
+    );
+    assert_eq!(new_dt.date(), dt.date());
+    assert_eq!(new_dt.offset(), dt.offset());
+
+    let new_dt = dt.replace_time_with_millis(86_399_999).unwrap();
+    assert_eq!(
+        new_dt.time(),
+        Time::from_hms_milli(23, 59, 59, 999).unwrap()
+    );
+
+    assert_eq!(
+        dt.replace_time_with_millis(0).unwrap().time(),
+        Time::MIDNIGHT
+    );
+
+    assert!(dt.replace_time_with_millis(-1).is_err());
+    assert!(dt.replace_time_with_millis(86_400_000).is_err());
+}
+
+#[test]
+fn test_with_hour_minute_second() {
+    let dt = create_test_datetime().replace_millisecond(250).unwrap(); // 14:30:45.250
+
+    let new_dt = dt.with_hour(9).unwrap();
+    assert_eq!(new_dt.time(), Time::from_hms_milli(9, 30, 45, 250).unwrap());
+    assert_eq!(new_dt.date(), dt.date());
+    assert_eq!(new_dt.offset(), dt.offset());
+
+    let new_dt = dt.with_minute(0).unwrap();
+    assert_eq!(new_dt.time(), Time::from_hms_milli(14, 0, 45, 250).unwrap());
+
+    let new_dt = dt.with_second(59).unwrap();
+    assert_eq!(
+        new_dt.time(),
+        Time::from_hms_milli(14, 30, 59, 250).unwrap()
+    );
+
+    assert!(dt.with_hour(24).is_err());
+    assert!(dt.with_minute(60).is_err());
+    assert!(dt.with_second(60).is_err());
+}
+
 #[test]
 fn test_align_to() {
     let dt = create_test_datetime();
@@ -96,10 +567,102 @@ fn test_align_to() {
     // 24 * 3600 is a valid alignment interval (24 hours)
     let aligned = dt.align_to(24 * 3600).unwrap();
     assert_eq!(aligned.hour(), 0);
+
+    // Same boundaries as align_to_floor; the sign of the interval is ignored
+    assert_eq!(dt.align_to(900).unwrap(), dt.align_to_floor(900).unwrap());
+    assert_eq!(dt.align_to(-900).unwrap(), dt.align_to_floor(900).unwrap());
+    assert!(dt.align_to_floor(-900).is_err());
     assert_eq!(aligned.minute(), 0);
     assert_eq!(aligned.second(), 0);
 }
 
+#[test]
+fn test_align_to_mode() {
+    use ext_time::AlignMode;
+
+    let dt = create_test_datetime(); // 2024-03-15 14:30:45 +08:00
+    let at = |h, m, s| dt.replace_time(Time::from_hms(h, m, s).unwrap());
+
+    let t = at(14, 32, 0);
+    assert_eq!(
+        t.align_to_mode(300, AlignMode::Floor).unwrap(),
+        at(14, 30, 0)
+    );
+    assert_eq!(
+        t.align_to_mode(300, AlignMode::Ceil).unwrap(),
+        at(14, 35, 0)
+    );
+    assert_eq!(
+        t.align_to_mode(300, AlignMode::Round).unwrap(),
+        at(14, 30, 0)
+    );
+    assert_eq!(
+        at(14, 32, 30).align_to_mode(300, AlignMode::Round).unwrap(),
+        at(14, 35, 0)
+    );
+
+    // Already aligned values are kept
+    let t = at(14, 35, 0);
+    assert_eq!(t.align_to_mode(300, AlignMode::Ceil).unwrap(), t);
+
+    // Subseconds count as off-grid
+    let t = at(14, 35, 0).replace_millisecond(1).unwrap();
+    assert_eq!(
+        t.align_to_mode(300, AlignMode::Ceil).unwrap(),
+        at(14, 40, 0)
+    );
+    assert_eq!(
+        t.align_to_mode(300, AlignMode::Floor).unwrap(),
+        at(14, 35, 0)
+    );
+
+    // Ceil past midnight advances the date
+    let t = at(23, 58, 0);
+    let aligned = t.align_to_mode(300, AlignMode::Ceil).unwrap();
+    assert_eq!(
+        aligned.date(),
+        Date::from_calendar_date(2024, time::Month::March, 16).unwrap()
+    );
+    assert_eq!(aligned.time(), Time::MIDNIGHT);
+    assert_eq!(aligned.offset(), dt.offset());
+    assert_eq!(t.align_to_mode(300, AlignMode::Round).unwrap(), aligned);
+
+    // Year boundary
+    let t = dt
+        .replace_date(Date::from_calendar_date(2024, time::Month::December, 31).unwrap())
+        .replace_time(Time::from_hms(23, 59, 1).unwrap());
+    let aligned = t.align_to_mode(60, AlignMode::Ceil).unwrap();
+    assert_eq!(aligned.year(), 2025);
+    assert_eq!(aligned.ordinal(), 1);
+
+    assert!(dt.align_to_mode(0, AlignMode::Floor).is_err());
+    assert!(dt.align_to_mode(-300, AlignMode::Ceil).is_err());
+}
+
+#[test]
+fn test_snap_to() {
+    let dt = create_test_datetime(); // 14:30:45 +08:00
+    let at = |h, m, s| dt.replace_time(Time::from_hms(h, m, s).unwrap());
+
+    assert_eq!(at(14, 32, 0).snap_to(300).unwrap(), at(14, 30, 0));
+    assert_eq!(at(14, 33, 0).snap_to(300).unwrap(), at(14, 35, 0));
+    // Tie goes to floor
+    assert_eq!(at(14, 32, 30).snap_to(300).unwrap(), at(14, 30, 0));
+    assert_eq!(at(14, 35, 0).snap_to(300).unwrap(), at(14, 35, 0));
+
+    // Subseconds are considered
+    let dt2 = at(14, 32, 30).replace_millisecond(1).unwrap();
+    assert_eq!(dt2.snap_to(300).unwrap(), at(14, 35, 0));
+
+    // Epoch-based and offset preserved: daily grid is UTC midnight, i.e. 08:00 at +08:00
+    let snapped = at(14, 0, 0).snap_to(86400).unwrap();
+    assert_eq!(snapped, at(8, 0, 0));
+    assert_eq!(snapped.offset(), dt.offset());
+
+    assert!(dt.snap_to(0).is_err());
+    assert!(dt.snap_to(-300).is_err());
+}
+
 #[test]
 fn test_next_day() {
     let dt = create_test_datetime();
@@ -242,3 +805,936 @@ fn test_duration_to_time_just_before_midnight() {
     // Expected duration: 1 second
     assert_eq!(duration.whole_seconds(), 1);
 }
+
+#[test]
+fn test_duration_to() {
+    let dt = create_test_datetime(); // 14:30:45
+    for (h, m, s) in [(20, 0, 0), (8, 0, 0), (14, 30, 45), (0, 0, 0)] {
+        assert_eq!(
+            dt.duration_to(Time::from_hms(h, m, s).unwrap()),
+            dt.duration_to_time(h, m, s)
+        );
+    }
+
+    assert_eq!(
+        dt.duration_to(Time::from_hms(14, 30, 45).unwrap()),
+        time::Duration::ZERO
+    );
+    let late = dt.replace_time(Time::from_hms(23, 59, 59).unwrap());
+    assert_eq!(late.duration_to(Time::MIDNIGHT), time::Duration::seconds(1));
+    assert_eq!(
+        dt.duration_to(Time::from_hms(14, 30, 44).unwrap()),
+        time::Duration::hours(24) - time::Duration::seconds(1)
+    );
+}
+
+#[test]
+fn test_parse_error_detailed_position() {
+    use ext_time::OffsetDateTimeError;
+
+    match OffsetDateTime::from_simple_detailed("20240315_1x30", 8) {
+        Err(OffsetDateTimeError::ParseErrorDetailed {
+            input, position, ..
+        }) => {
+            assert_eq!(input, "20240315_1x30");
+            assert_eq!(position, Some(9));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Positions are relative to the argument that failed
+    match OffsetDateTime::from_date_time_detailed("20240315", "1x:30:45", 0, 8) {
+        Err(OffsetDateTimeError::ParseErrorDetailed {
+            input, position, ..
+        }) => {
+            assert_eq!(input, "1x:30:45");
+            assert_eq!(position, Some(0));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    match OffsetDateTime::from_date_time_detailed("2024031", "14:30:45", 0, 8) {
+        Err(OffsetDateTimeError::ParseErrorDetailed {
+            input, position, ..
+        }) => {
+            assert_eq!(input, "2024031");
+            assert_eq!(position, Some(6));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert!(OffsetDateTime::from_date_time_detailed("20240315", "14:30:45", 1000, 8).is_err());
+    assert_eq!(
+        OffsetDateTime::from_date_time_detailed("20240315", "14:30:45", 250, 8).unwrap(),
+        OffsetDateTime::from_date_time("20240315", "14:30:45", 250, 8).unwrap()
+    );
+
+    match OffsetDateTime::from_pattern_detailed(
+        "2024-03-15 14:3x:45 +08",
+        "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]",
+    ) {
+        Err(OffsetDateTimeError::ParseErrorDetailed { position, .. }) => {
+            assert_eq!(position, Some(14))
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    match <OffsetDateTime as ExtOffsetDateTime>::convert_to_dot_date_detailed("2024a315") {
+        Err(OffsetDateTimeError::ParseErrorDetailed {
+            input, position, ..
+        }) => {
+            assert_eq!(input, "2024a315");
+            assert_eq!(position, Some(4));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // All components match but the date does not exist
+    match <OffsetDateTime as ExtOffsetDateTime>::convert_to_dot_date_detailed("20240230") {
+        Err(OffsetDateTimeError::ParseErrorDetailed { position, .. }) => {
+            assert_eq!(position, None)
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_error_kept_for_existing_parsers() {
+    use ext_time::OffsetDateTimeError;
+
+    assert!(matches!(
+        OffsetDateTime::from_simple("20240315_1x30", 8),
+        Err(OffsetDateTimeError::ParseError(_))
+    ));
+    assert!(matches!(
+        OffsetDateTime::from_date_time("20240315", "1x:30:45", 0, 8),
+        Err(OffsetDateTimeError::ParseError(_))
+    ));
+    assert!(matches!(
+        <OffsetDateTime as ExtOffsetDateTime>::convert_to_dot_date("2024a315"),
+        Err(OffsetDateTimeError::ParseError(_))
+    ));
+    assert!(matches!(
+        OffsetDateTime::from_pattern("bad", "[year]-[month]-[day]"),
+        Err(OffsetDateTimeError::ParseError(_))
+    ));
+}
+
+#[test]
+fn test_countdown_to() {
+    let dt = create_test_datetime(); // 14:30:45
+    assert_eq!(
+        dt.countdown_to(Time::from_hms(15, 0, 0).unwrap()),
+        "00:29:15"
+    );
+    assert_eq!(
+        dt.countdown_to(Time::from_hms(14, 30, 45).unwrap()),
+        "00:00:00"
+    );
+    // Cross-day
+    assert_eq!(
+        dt.countdown_to(Time::from_hms(9, 30, 0).unwrap()),
+        "18:59:15"
+    );
+
+    let dt = dt.replace_millisecond(500).unwrap();
+    assert_eq!(
+        dt.countdown_to(Time::from_hms(15, 0, 0).unwrap()),
+        "00:29:14"
+    );
+}
+
+#[test]
+fn test_until_next_day() {
+    let dt = create_test_datetime(); // 14:30:45
+    assert_eq!(
+        dt.until_next_day(),
+        time::Duration::hours(9) + time::Duration::minutes(29) + time::Duration::seconds(15)
+    );
+
+    let dt = dt.replace_time(Time::from_hms(23, 59, 59).unwrap());
+    assert_eq!(dt.until_next_day(), time::Duration::seconds(1));
+
+    // Exactly at midnight a full day remains, unlike duration_to_time
+    let dt = dt.replace_time(Time::MIDNIGHT);
+    assert_eq!(dt.until_next_day(), time::Duration::hours(24));
+    assert_eq!(dt.duration_to_time(0, 0, 0), time::Duration::ZERO);
+}
+
+#[test]
+fn test_window_coverage() {
+    let day = create_test_datetime().replace_time(Time::MIDNIGHT);
+    let at = |h: i64| day + time::Duration::hours(h);
+
+    assert_eq!(day.window_coverage(at(6), at(12)), 0.25);
+    assert_eq!(day.window_coverage(at(-6), at(30)), 1.0);
+    assert_eq!(day.window_coverage(at(18), at(30)), 0.25);
+    assert_eq!(day.window_coverage(at(24), at(30)), 0.0);
+    assert_eq!(day.window_coverage(at(-6), at(0)), 0.0);
+    assert_eq!(day.window_coverage(at(12), at(6)), 0.0);
+
+    // Window in another offset is compared by instant
+    let utc_start = at(6).to_offset(UtcOffset::UTC);
+    assert_eq!(day.window_coverage(utc_start, at(12)), 0.25);
+}
+
+#[test]
+fn test_within_last_and_next() {
+    let now = create_test_datetime();
+    let d = time::Duration::minutes(5);
+    let ns = time::Duration::nanoseconds(1);
+
+    assert!(now.within_last(d, now));
+    assert!((now - d).within_last(d, now));
+    assert!(!(now - d - ns).within_last(d, now));
+    assert!(!(now + ns).within_last(d, now));
+
+    assert!(now.within_next(d, now));
+    assert!((now + d).within_next(d, now));
+    assert!(!(now + d + ns).within_next(d, now));
+    assert!(!(now - ns).within_next(d, now));
+
+    // Offsets do not matter
+    let utc = (now - time::Duration::minutes(1)).to_offset(UtcOffset::UTC);
+    assert!(utc.within_last(d, now));
+}
+
+#[test]
+fn test_clamp_dt() {
+    let dt = create_test_datetime(); // 2024-03-15 14:30:45 +08:00
+    let min = dt.replace_time(Time::from_hms(9, 0, 0).unwrap());
+    let max = dt.replace_time(Time::from_hms(15, 0, 0).unwrap());
+
+    assert_eq!(dt.clamp_dt(min, max), dt);
+    assert_eq!(dt.replace_hour(8).unwrap().clamp_dt(min, max), min);
+    assert_eq!(dt.replace_hour(16).unwrap().clamp_dt(min, max), max);
+
+    // Bounds in a different offset are compared by instant
+    let utc_max = max.to_offset(UtcOffset::UTC); // 07:00 UTC
+    assert_eq!(dt.clamp_dt(min, utc_max), dt);
+    let utc_min = dt.replace_hour(15).unwrap().to_offset(UtcOffset::UTC);
+    assert_eq!(dt.clamp_dt(utc_min, utc_max.next_hour()), utc_min);
+}
+
+#[test]
+fn test_is_first_and_last_of_month() {
+    let dt = create_test_datetime();
+    assert!(!dt.is_first_of_month());
+    assert!(!dt.is_last_of_month());
+
+    assert!(dt.replace_day(1).unwrap().is_first_of_month());
+    assert!(dt.replace_day(31).unwrap().is_last_of_month());
+    assert!(!dt.replace_day(30).unwrap().is_last_of_month());
+
+    // Leap year February
+    let feb = dt
+        .replace_day(1)
+        .unwrap()
+        .replace_month(time::Month::February)
+        .unwrap();
+    assert!(!feb.replace_day(28).unwrap().is_last_of_month());
+    assert!(feb.replace_day(29).unwrap().is_last_of_month());
+    let feb = feb.replace_year(2023).unwrap();
+    assert!(feb.replace_day(28).unwrap().is_last_of_month());
+
+    let apr = dt.replace_month(time::Month::April).unwrap();
+    assert!(apr.replace_day(30).unwrap().is_last_of_month());
+}
+
+#[test]
+fn test_start_of_week() {
+    use time::Weekday;
+
+    let dt = create_test_datetime(); // Friday 2024-03-15 14:30:45 +08:00
+
+    let start = dt.start_of_week_monday();
+    assert_eq!(
+        start.date(),
+        Date::from_calendar_date(2024, time::Month::March, 11).unwrap()
+    );
+    assert_eq!(start.time(), Time::MIDNIGHT);
+    assert_eq!(start.offset(), dt.offset());
+
+    let start = dt.start_of_week(Weekday::Sunday);
+    assert_eq!(
+        start.date(),
+        Date::from_calendar_date(2024, time::Month::March, 10).unwrap()
+    );
+
+    // Already on the first day
+    let start = dt.start_of_week(Weekday::Friday);
+    assert_eq!(start.date(), dt.date());
+
+    let start = dt.start_of_week(Weekday::Saturday);
+    assert_eq!(
+        start.date(),
+        Date::from_calendar_date(2024, time::Month::March, 9).unwrap()
+    );
+}
+
+#[test]
+fn test_start_and_end_of_quarter() {
+    let dt = create_test_datetime(); // 2024-03-15 14:30:45 +08:00
+
+    let start = dt.start_of_quarter();
+    assert_eq!(
+        start.date(),
+        Date::from_calendar_date(2024, time::Month::January, 1).unwrap()
+    );
+    assert_eq!(start.time(), Time::MIDNIGHT);
+    assert_eq!(start.offset(), dt.offset());
+
+    let end = dt.start_of_quarter().end_of_quarter();
+    assert_eq!(
+        end.date(),
+        Date::from_calendar_date(2024, time::Month::March, 31).unwrap()
+    );
+    assert_eq!(
+        end.time(),
+        Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap()
+    );
+    assert_eq!(end.offset(), dt.offset());
+
+    let dt = dt.replace_month(time::Month::November).unwrap();
+    assert_eq!(dt.start_of_quarter().month(), time::Month::October);
+    assert_eq!(dt.end_of_quarter().month(), time::Month::December);
+    assert_eq!(dt.end_of_quarter().day(), 31);
+
+    let dt = dt.replace_month(time::Month::May).unwrap();
+    assert_eq!(dt.start_of_quarter().month(), time::Month::April);
+    assert_eq!(dt.end_of_quarter().month(), time::Month::June);
+    assert_eq!(dt.end_of_quarter().day(), 30);
+}
+
+#[test]
+fn test_system_time_roundtrip() {
+    use std::time::{Duration as StdDuration, UNIX_EPOCH};
+
+    let dt = create_test_datetime().replace_millisecond(250).unwrap();
+    let st = dt.to_system_time().unwrap();
+    assert_eq!(
+        st.duration_since(UNIX_EPOCH).unwrap(),
+        StdDuration::new(dt.unix_timestamp() as u64, 250_000_000)
+    );
+
+    let back = OffsetDateTime::from_system_time(st, 8).unwrap();
+    assert_eq!(back, dt);
+    assert_eq!(back.offset().whole_hours(), 8);
+
+    // Pre-epoch
+    let st = UNIX_EPOCH - StdDuration::from_millis(1500);
+    let dt = OffsetDateTime::from_system_time(st, 0).unwrap();
+    assert_eq!(dt.unix_timestamp(), -2);
+    assert_eq!(dt.millisecond(), 500);
+    assert_eq!(dt.to_system_time().unwrap(), st);
+
+    assert!(OffsetDateTime::from_system_time(st, 30).is_err());
+
+    // Representable as SystemTime, but beyond year 9999
+    let st = UNIX_EPOCH + StdDuration::from_secs(1 << 40);
+    assert!(matches!(
+        OffsetDateTime::from_system_time(st, 0),
+        Err(ext_time::OffsetDateTimeError::SystemTimeOutOfRange)
+    ));
+}
+
+#[test]
+fn test_calendar_days_between() {
+    let dt = create_test_datetime();
+    let late = dt.replace_time(Time::from_hms(23, 59, 0).unwrap());
+    let early_next = dt.next_day().replace_time(Time::from_hms(0, 1, 0).unwrap());
+
+    assert_eq!(late.calendar_days_between(&early_next), 1);
+    assert_eq!(early_next.calendar_days_between(&late), -1);
+    assert_eq!(dt.calendar_days_between(&late), 0);
+    assert_eq!(
+        dt.calendar_days_between(&(dt + time::Duration::days(30))),
+        30
+    );
+
+    // Dates are taken in each value's own offset: 15th 23:59 at +08:00 is 15:59 UTC on the 15th
+    let late_utc = late.to_offset(UtcOffset::UTC);
+    assert_eq!(dt.calendar_days_between(&late_utc), 0);
+    let early_next_utc = early_next.to_offset(UtcOffset::UTC); // 15th 16:01 UTC
+    assert_eq!(late.calendar_days_between(&early_next_utc), 0);
+}
+
+#[test]
+fn test_age_since() {
+    let offset = UtcOffset::from_hms(8, 0, 0).unwrap();
+    let at = |y: i32, m: time::Month, d: u8, h: u8| {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(y, m, d).unwrap(),
+            Time::from_hms(h, 0, 0).unwrap(),
+        )
+        .assume_offset(offset)
+    };
+    use time::Month::*;
+
+    let now = at(2024, March, 15, 12);
+    assert_eq!(now.age_since(at(1990, March, 15, 12)).unwrap(), (34, 0, 0));
+    assert_eq!(
+        now.age_since(at(1990, March, 16, 12)).unwrap(),
+        (33, 11, 28)
+    );
+    assert_eq!(
+        now.age_since(at(2023, December, 20, 12)).unwrap(),
+        (0, 2, 24)
+    );
+
+    // Incomplete last day is not counted
+    assert_eq!(now.age_since(at(2024, March, 14, 13)).unwrap(), (0, 0, 0));
+    assert_eq!(now.age_since(at(2024, March, 14, 12)).unwrap(), (0, 0, 1));
+
+    // End-of-month clamping
+    assert_eq!(
+        at(2023, March, 1, 0)
+            .age_since(at(2023, January, 31, 0))
+            .unwrap(),
+        (0, 1, 1)
+    );
+    assert_eq!(
+        at(2024, February, 29, 0)
+            .age_since(at(2024, January, 31, 0))
+            .unwrap(),
+        (0, 1, 0)
+    );
+
+    // Offsets are normalized to self's offset
+    let past_utc = at(2023, March, 15, 12).to_offset(UtcOffset::UTC);
+    assert_eq!(now.age_since(past_utc).unwrap(), (1, 0, 0));
+
+    // Future input
+    assert_eq!(now.age_since(at(2025, January, 1, 0)).unwrap(), (0, 0, 0));
+
+    // Far past that cannot be expressed in self's offset
+    let min = PrimitiveDateTime::MIN.assume_offset(UtcOffset::UTC);
+    assert!(
+        now.to_offset(UtcOffset::from_hms(-1, 0, 0).unwrap())
+            .age_since(min)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_from_iso_week() {
+    use time::Weekday;
+
+    let dt = OffsetDateTime::from_iso_week(
+        2024,
+        11,
+        Weekday::Friday,
+        Time::from_hms(14, 30, 45).unwrap(),
+        8,
+    )
+    .unwrap();
+    assert_eq!(dt, create_test_datetime());
+
+    // ISO week 1 of 2025 starts in 2024
+    let dt = OffsetDateTime::from_iso_week(2025, 1, Weekday::Monday, Time::MIDNIGHT, 0).unwrap();
+    assert_eq!(
+        dt.date(),
+        Date::from_calendar_date(2024, time::Month::December, 30).unwrap()
+    );
+
+    assert!(OffsetDateTime::from_iso_week(2024, 53, Weekday::Monday, Time::MIDNIGHT, 0).is_err());
+    assert!(OffsetDateTime::from_iso_week(2024, 1, Weekday::Monday, Time::MIDNIGHT, 30).is_err());
+}
+
+#[test]
+fn test_iso_week_string() {
+    let dt = create_test_datetime();
+    assert_eq!(dt.to_iso_week_string(), "2024-W11-5");
+
+    // Week-based year differs from the calendar year at the boundary
+    let dt = OffsetDateTime::from_iso_week_string("2025-W01-1", 8).unwrap();
+    assert_eq!(
+        dt.date(),
+        Date::from_calendar_date(2024, time::Month::December, 30).unwrap()
+    );
+    assert_eq!(dt.time(), Time::MIDNIGHT);
+    assert_eq!(dt.offset().whole_hours(), 8);
+    assert_eq!(dt.to_iso_week_string(), "2025-W01-1");
+
+    let dt = OffsetDateTime::from_iso_week_string("2024-W11-5", 8).unwrap();
+    assert_eq!(dt.date(), create_test_datetime().date());
+
+    assert!(OffsetDateTime::from_iso_week_string("2024-W53-1", 8).is_err());
+    assert!(OffsetDateTime::from_iso_week_string("2024-W11-8", 8).is_err());
+    assert!(OffsetDateTime::from_iso_week_string("2024-11-5", 8).is_err());
+    assert!(OffsetDateTime::from_iso_week_string("2024-W11-5", 30).is_err());
+}
+
+#[test]
+fn test_business_days_between() {
+    use std::collections::HashSet;
+
+    let start = create_test_datetime(); // Friday 2024-03-15 14:30:45
+    let end = start + time::Duration::days(7); // next Friday
+    let holidays: HashSet<Date> = [Date::from_calendar_date(2024, time::Month::March, 19).unwrap()]
+        .into_iter()
+        .collect();
+
+    let days: Vec<OffsetDateTime> =
+        OffsetDateTime::business_days_between(start, end, &holidays).collect();
+    let day_numbers: Vec<u8> = days.iter().map(|d| d.day()).collect();
+    assert_eq!(day_numbers, vec![15, 18, 20, 21, 22]);
+    assert!(days.iter().all(|d| d.time() == start.time()));
+
+    // End just before the last day's time excludes it
+    let end = end - time::Duration::seconds(1);
+    assert_eq!(
+        OffsetDateTime::business_days_between(start, end, &holidays).count(),
+        4
+    );
+
+    // Empty when end is before start
+    assert_eq!(
+        OffsetDateTime::business_days_between(start, start - time::Duration::days(1), &holidays)
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_business_seconds_between() {
+    use std::collections::HashSet;
+
+    let open = Time::from_hms(9, 0, 0).unwrap();
+    let close = Time::from_hms(17, 0, 0).unwrap();
+    let friday = create_test_datetime(); // Friday 2024-03-15 14:30:45 +08:00
+    let no_holidays = HashSet::new();
+
+    // Same day, partially in session
+    let end = friday.replace_time(Time::from_hms(18, 0, 0).unwrap());
+    let expected = (2 * 3600 + 29 * 60 + 15) as i64;
+    assert_eq!(
+        OffsetDateTime::business_seconds_between(friday, end, open, close, &no_holidays),
+        expected
+    );
+
+    // Friday afternoon to Monday 10:00 skips the weekend
+    let monday = friday
+        .replace_day(18)
+        .unwrap()
+        .replace_time(Time::from_hms(10, 0, 0).unwrap());
+    assert_eq!(
+        OffsetDateTime::business_seconds_between(friday, monday, open, close, &no_holidays),
+        expected + 3600
+    );
+
+    // Monday as holiday
+    let holidays: HashSet<Date> = [monday.date()].into_iter().collect();
+    assert_eq!(
+        OffsetDateTime::business_seconds_between(friday, monday, open, close, &holidays),
+        expected
+    );
+    assert!(!monday.is_business_day(&holidays));
+    assert!(monday.is_business_day(&no_holidays));
+    assert!(
+        !friday
+            .replace_day(16)
+            .unwrap()
+            .is_business_day(&no_holidays)
+    );
+
+    // End in a different offset is compared by instant
+    let end_utc = end.to_offset(UtcOffset::UTC);
+    assert_eq!(
+        OffsetDateTime::business_seconds_between(friday, end_utc, open, close, &no_holidays),
+        expected
+    );
+
+    // Overnight session 21:00 - 02:00 opened on Friday
+    let night_open = Time::from_hms(21, 0, 0).unwrap();
+    let night_close = Time::from_hms(2, 0, 0).unwrap();
+    let saturday = friday
+        .replace_day(16)
+        .unwrap()
+        .replace_time(Time::from_hms(12, 0, 0).unwrap());
+    assert_eq!(
+        OffsetDateTime::business_seconds_between(
+            friday,
+            saturday,
+            night_open,
+            night_close,
+            &no_holidays
+        ),
+        5 * 3600
+    );
+
+    assert_eq!(
+        OffsetDateTime::business_seconds_between(end, friday, open, close, &no_holidays),
+        0
+    );
+}
+
+#[test]
+fn test_from_timestamp_auto() {
+    let (dt, is_millis) = OffsetDateTime::from_timestamp_auto(1_710_484_245, 8).unwrap();
+    assert_eq!(dt, create_test_datetime());
+    assert!(!is_millis);
+
+    let (dt, is_millis) = OffsetDateTime::from_timestamp_auto(1_710_484_245_123, 8).unwrap();
+    assert_eq!(dt.unix_timestamp(), 1_710_484_245);
+    assert_eq!(dt.millisecond(), 123);
+    assert_eq!(dt.offset().whole_hours(), 8);
+    assert!(is_millis);
+
+    // Negative millisecond timestamps before the epoch
+    let (dt, is_millis) = OffsetDateTime::from_timestamp_auto(-100_000_000_000, 0).unwrap();
+    assert_eq!(dt.unix_timestamp(), -100_000_000);
+    assert!(is_millis);
+
+    assert!(OffsetDateTime::from_timestamp_auto(1_710_484_245, 30).is_err());
+}
+
+#[test]
+fn test_seconds_remaining_today() {
+    assert_eq!(OffsetDateTime::seconds_in_day(), 86400);
+
+    let dt = create_test_datetime(); // 14:30:45
+    assert_eq!(dt.seconds_remaining_today(), 9 * 3600 + 29 * 60 + 15);
+    assert_eq!(
+        dt.replace_time(Time::MIDNIGHT).seconds_remaining_today(),
+        86400
+    );
+    let late = dt.replace_time(Time::from_hms_milli(23, 59, 59, 500).unwrap());
+    assert_eq!(late.seconds_remaining_today(), 0);
+}
+
+#[test]
+fn test_same_week() {
+    use time::Weekday;
+
+    let offset = UtcOffset::from_hms(8, 0, 0).unwrap();
+    let at = |y, m, d| {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(y, time::Month::try_from(m).unwrap(), d).unwrap(),
+            Time::from_hms(12, 0, 0).unwrap(),
+        )
+        .assume_offset(offset)
+    };
+
+    // 2024-12-30 (Mon) and 2025-01-05 (Sun) are both in ISO 2025-W01
+    assert!(at(2024, 12, 30).same_week(&at(2025, 1, 5), Weekday::Monday));
+    // 2024-12-29 (Sun) is the last day of 2024-W52
+    assert!(!at(2024, 12, 29).same_week(&at(2024, 12, 30), Weekday::Monday));
+    assert!(at(2024, 12, 23).same_week(&at(2024, 12, 29), Weekday::Monday));
+
+    // Sunday-first weeks split differently
+    assert!(at(2024, 12, 29).same_week(&at(2025, 1, 4), Weekday::Sunday));
+    assert!(!at(2025, 1, 4).same_week(&at(2025, 1, 5), Weekday::Sunday));
+
+    // Other is compared in self's offset: Sunday 20:00 UTC is Monday 04:00 +08:00
+    let utc_sunday = at(2024, 12, 29)
+        .to_offset(UtcOffset::UTC)
+        .replace_time(Time::from_hms(20, 0, 0).unwrap());
+    assert!(at(2024, 12, 30).same_week(&utc_sunday, Weekday::Monday));
+}
+
+#[test]
+fn test_align_to_rounding_variants() {
+    let dt = create_test_datetime(); // 14:30:45
+    assert_eq!(
+        dt.align_to_floor(60).unwrap().time(),
+        Time::from_hms(14, 30, 0).unwrap()
+    );
+    assert_eq!(
+        dt.align_to_ceil(60).unwrap().time(),
+        Time::from_hms(14, 31, 0).unwrap()
+    );
+    assert_eq!(
+        dt.align_to_nearest(90).unwrap().time(),
+        Time::from_hms(14, 31, 30).unwrap()
+    ); // 45s past 14:30:00 is a tie on a 90s grid
+    assert_eq!(dt.align_to(60).unwrap(), dt.align_to_floor(60).unwrap());
+
+    // Already aligned datetimes are unchanged by ceil
+    let aligned = dt.align_to_floor(300).unwrap();
+    assert_eq!(aligned.align_to_ceil(300).unwrap(), aligned);
+
+    // Ceil carries into the next day
+    let late = dt.replace_time(Time::from_hms(23, 58, 0).unwrap());
+    let ceiled = late.align_to_ceil(300).unwrap();
+    assert_eq!(ceiled.day(), 16);
+    assert_eq!(ceiled.time(), Time::MIDNIGHT);
+
+    assert!(dt.align_to_floor(0).is_err());
+    assert!(dt.align_to_ceil(-60).is_err());
+    assert!(dt.align_to_nearest(0).is_err());
+}
+
+#[test]
+fn test_diff_components() {
+    let dt = create_test_datetime();
+    let later = dt
+        + time::Duration::days(2)
+        + time::Duration::hours(3)
+        + time::Duration::minutes(4)
+        + time::Duration::seconds(5)
+        + time::Duration::milliseconds(999);
+    assert_eq!(dt.diff_components(&later), (2, 3, 4, 5));
+    assert_eq!(dt.diff_components(&dt), (0, 0, 0, 0));
+
+    // Offsets don't matter, only the instants
+    let utc = later.to_offset(UtcOffset::UTC);
+    assert_eq!(dt.diff_components(&utc), (2, 3, 4, 5));
+
+    // Negative differences floor the days
+    let earlier = dt - time::Duration::hours(1);
+    assert_eq!(dt.diff_components(&earlier), (-1, 23, 0, 0));
+    let earlier = dt - time::Duration::days(1) - time::Duration::seconds(30);
+    assert_eq!(dt.diff_components(&earlier), (-2, 23, 59, 30));
+}
+
+#[test]
+fn test_nth_tick() {
+    let dt = create_test_datetime();
+    assert_eq!(dt.nth_tick(300, 0).unwrap(), dt);
+    assert_eq!(
+        dt.nth_tick(300, 3).unwrap().time(),
+        Time::from_hms(14, 45, 45).unwrap()
+    );
+    assert_eq!(
+        dt.nth_tick(300, -6).unwrap().time(),
+        Time::from_hms(14, 0, 45).unwrap()
+    );
+
+    // Crosses into the next day instead of wrapping
+    let tick = dt.nth_tick(3600, 12).unwrap();
+    assert_eq!(tick.day(), 16);
+    assert_eq!(tick.time(), Time::from_hms(2, 30, 45).unwrap());
+
+    // Overflowing offset or result is an error, not a panic
+    assert!(matches!(
+        dt.nth_tick(i64::MAX, 2),
+        Err(ext_time::OffsetDateTimeError::AddTimeError(_))
+    ));
+    assert!(dt.nth_tick(86400, 1_000_000_000).is_err());
+}
+
+#[test]
+fn test_floor_and_ceil_to() {
+    use time::Duration;
+
+    let dt = create_test_datetime().replace_millisecond(300).unwrap(); // 14:30:45.300 +08:00
+    assert_eq!(
+        dt.floor_to(Duration::milliseconds(250)).unwrap().time(),
+        Time::from_hms_milli(14, 30, 45, 250).unwrap()
+    );
+    assert_eq!(
+        dt.ceil_to(Duration::milliseconds(250)).unwrap().time(),
+        Time::from_hms_milli(14, 30, 45, 500).unwrap()
+    );
+    assert_eq!(
+        dt.floor_to(Duration::minutes(15)).unwrap().time(),
+        Time::from_hms(14, 30, 0).unwrap()
+    );
+    assert_eq!(
+        dt.ceil_to(Duration::minutes(15)).unwrap().time(),
+        Time::from_hms(14, 45, 0).unwrap()
+    );
+
+    // Day units are epoch-based, i.e. UTC midnight (08:00 at +08:00)
+    let floored = dt.floor_to(Duration::DAY).unwrap();
+    assert_eq!(floored.time(), Time::from_hms(8, 0, 0).unwrap());
+    assert_eq!(floored.offset(), dt.offset());
+    assert_eq!(dt.ceil_to(Duration::DAY).unwrap(), floored + Duration::DAY);
+
+    // Already aligned values are unchanged
+    let aligned = dt.floor_to(Duration::SECOND).unwrap();
+    assert_eq!(aligned.ceil_to(Duration::SECOND).unwrap(), aligned);
+    assert_eq!(aligned.floor_to(Duration::SECOND).unwrap(), aligned);
+
+    // Pre-epoch values floor away from zero
+    let before = OffsetDateTime::UNIX_EPOCH - Duration::milliseconds(1500);
+    assert_eq!(
+        before.floor_to(Duration::SECOND).unwrap(),
+        OffsetDateTime::UNIX_EPOCH - Duration::seconds(2)
+    );
+
+    assert!(dt.floor_to(Duration::ZERO).is_err());
+    assert!(dt.ceil_to(Duration::seconds(-1)).is_err());
+}
+
+#[test]
+fn test_prev_aligned() {
+    let dt = create_test_datetime(); // 14:30:45
+    assert_eq!(
+        dt.prev_aligned(300).unwrap().time(),
+        Time::from_hms(14, 30, 0).unwrap()
+    );
+
+    let on_boundary = dt.replace_time(Time::from_hms(14, 30, 0).unwrap());
+    assert_eq!(
+        on_boundary.prev_aligned(300).unwrap().time(),
+        Time::from_hms(14, 25, 0).unwrap()
+    );
+
+    // Midnight steps back into the previous date
+    let midnight = dt.replace_time(Time::MIDNIGHT);
+    let prev = midnight.prev_aligned(300).unwrap();
+    assert_eq!(prev.day(), 14);
+    assert_eq!(prev.time(), Time::from_hms(23, 55, 0).unwrap());
+    assert_eq!(prev.offset(), dt.offset());
+
+    assert!(dt.prev_aligned(0).is_err());
+    assert!(dt.prev_aligned(-60).is_err());
+}
+
+#[test]
+fn test_is_same_day_hour_second() {
+    let dt = create_test_datetime(); // 2024-03-15 14:30:45 +08:00
+    let beijing = UtcOffset::from_hms(8, 0, 0).unwrap();
+
+    // 00:30 +08:00 on the 16th is 16:30 UTC on the 15th
+    let after_midnight = dt
+        .replace_day(16)
+        .unwrap()
+        .replace_time(Time::from_hms(0, 30, 0).unwrap());
+    let utc = after_midnight.to_offset(UtcOffset::UTC);
+    assert!(!dt.is_same_day(&after_midnight));
+    assert!(!dt.is_same_day(&utc)); // compared in +08:00
+    assert!(utc.is_same_day(&dt)); // compared in UTC
+    assert!(dt.to_offset(UtcOffset::UTC).is_same_day(&utc));
+
+    let end_of_day = dt.replace_time(Time::from_hms(23, 59, 59).unwrap());
+    assert!(dt.is_same_day(&end_of_day));
+    assert!(!end_of_day.is_same_day(&after_midnight));
+
+    // Hours compare in the receiver's offset and require the same date
+    assert!(dt.is_same_hour(&dt.replace_minute(59).unwrap()));
+    assert!(dt.is_same_hour(&dt.to_offset(UtcOffset::UTC)));
+    assert!(!dt.is_same_hour(&dt.next_hour()));
+    assert!(!dt.is_same_hour(&dt.next_day()));
+
+    // Seconds ignore subseconds and offsets
+    let with_millis = dt.replace_millisecond(999).unwrap();
+    assert!(dt.is_same_second(&with_millis));
+    assert!(dt.is_same_second(&with_millis.to_offset(beijing)));
+    assert!(!dt.is_same_second(&dt.next_second()));
+    assert!(!dt.is_same_second(&dt.next_day()));
+}
+
+#[test]
+fn test_format_with_and_from_pattern() {
+    let dt = create_test_datetime();
+    assert_eq!(
+        dt.format_with("[year]/[month]/[day] [hour]:[minute]")
+            .unwrap(),
+        "2024/03/15 14:30"
+    );
+    // Cached pattern gives the same result
+    assert_eq!(
+        dt.format_with("[year]/[month]/[day] [hour]:[minute]")
+            .unwrap(),
+        "2024/03/15 14:30"
+    );
+
+    let pattern = "[year]/[month]/[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]";
+    let formatted = dt.format_with(pattern).unwrap();
+    assert_eq!(formatted, "2024/03/15 14:30:45 +08");
+    assert_eq!(
+        OffsetDateTime::from_pattern(&formatted, pattern).unwrap(),
+        dt
+    );
+
+    ext_time::clear_format_cache();
+    assert_eq!(
+        OffsetDateTime::from_pattern(&formatted, pattern).unwrap(),
+        dt
+    );
+
+    assert!(dt.format_with("[year").is_err());
+    assert!(OffsetDateTime::from_pattern("2024/03/15", "[unknown]").is_err());
+    assert!(OffsetDateTime::from_pattern("2024-03-15", pattern).is_err());
+}
+
+#[test]
+fn test_format_cache_eviction() {
+    let dt = create_test_datetime();
+    // More distinct patterns than the cache holds still format correctly
+    for i in 0..100 {
+        let pattern = format!("[year]-{}", i);
+        assert_eq!(dt.format_with(&pattern).unwrap(), format!("2024-{}", i));
+    }
+    assert_eq!(dt.format_with("[year]-0").unwrap(), "2024-0");
+}
+
+#[test]
+fn test_reset_hour_and_day() {
+    let dt = create_test_datetime().replace_millisecond(250).unwrap();
+
+    let hour = dt.reset_hour();
+    assert_eq!(hour.time(), Time::from_hms(14, 0, 0).unwrap());
+    assert_eq!(hour.date(), dt.date());
+    assert_eq!(hour.offset(), dt.offset());
+
+    let day = dt.reset_day();
+    assert_eq!(day.time(), Time::MIDNIGHT);
+    assert_eq!(day.date(), dt.date());
+    assert_eq!(day.offset(), dt.offset());
+
+    // Truncation happens in the value's own offset, not UTC
+    let ny = dt.to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap());
+    assert_eq!(ny.reset_day().day(), 15);
+    assert_eq!(ny.reset_day().hour(), 0);
+    assert_eq!(ny.reset_hour().hour(), 1);
+}
+
+#[test]
+fn test_prev_day_hour_minute_second() {
+    let dt = create_test_datetime();
+    assert_eq!(dt.prev_day().day(), 14);
+    assert_eq!(dt.prev_hour().hour(), 13);
+    assert_eq!(dt.prev_minute().minute(), 29);
+    assert_eq!(dt.prev_second().second(), 44);
+    assert_eq!(dt.prev_day().offset(), dt.offset());
+
+    let midnight = dt.replace_time(Time::MIDNIGHT);
+    let prev = midnight.prev_second();
+    assert_eq!(prev.day(), 14);
+    assert_eq!(prev.time(), Time::from_hms(23, 59, 59).unwrap());
+    assert_eq!(midnight.prev_hour().hour(), 23);
+
+    let march_first =
+        dt.replace_date(Date::from_calendar_date(2024, time::Month::March, 1).unwrap());
+    let prev = march_first.prev_day();
+    assert_eq!(prev.month(), time::Month::February);
+    assert_eq!(prev.day(), 29);
+}
+
+#[test]
+fn test_set_time_str() {
+    let dt = create_test_datetime();
+
+    let result = dt.set_time_str("09:15", false).unwrap();
+    assert_eq!(result.date(), dt.date());
+    assert_eq!(result.time(), Time::from_hms(9, 15, 0).unwrap());
+    assert_eq!(result.offset(), dt.offset());
+
+    let result = dt.set_time_str("23:59:59.500", false).unwrap();
+    assert_eq!(
+        result.time(),
+        Time::from_hms_milli(23, 59, 59, 500).unwrap()
+    );
+
+    assert!(dt.set_time_str("24:00", false).is_err());
+    assert!(dt.set_time_str("25:00", true).is_err());
+    assert!(dt.set_time_str("24:30", true).is_err());
+    assert!(dt.set_time_str("bad", true).is_err());
+}
+
+#[test]
+fn test_set_time_str_allow_24() {
+    let dt = create_test_datetime();
+
+    let end_of_day = dt.set_time_str("24:00", true).unwrap();
+    assert_eq!(end_of_day.day(), 16);
+    assert_eq!(end_of_day.time(), Time::MIDNIGHT);
+    assert_eq!(end_of_day.offset(), dt.offset());
+    assert_eq!(dt.set_time_str("24:00:00", true).unwrap(), end_of_day);
+
+    let result = dt.set_time_str("09:15", true).unwrap();
+    assert_eq!(result.day(), 15);
+    assert_eq!(result.time(), Time::from_hms(9, 15, 0).unwrap());
+}
@@ -0,0 +1,179 @@
+use ext_time::{
+    serde_parse_rfc3339, serde_parse_ts, serde_parse_ts_and_str, serde_parse_ts_millis,
+    serde_parse_ts_nanos, serde_parse_ts_nanos_str, serde_parse_ts_opt, serde_parse_ts_pair,
+    serde_t2rfc3339, serde_t2ts, serde_t2ts_and_str, serde_t2ts_millis, serde_t2ts_nanos,
+    serde_t2ts_nanos_str, serde_t2ts_opt, serde_t2ts_pair,
+};
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, UtcOffset, macros::datetime};
+
+fn create_test_datetime() -> OffsetDateTime {
+    datetime!(2024-03-15 14:30:45.123456789 +08:00)
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Ts {
+    #[serde(serialize_with = "serde_t2ts", deserialize_with = "serde_parse_ts")]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TsNanos {
+    #[serde(
+        serialize_with = "serde_t2ts_nanos",
+        deserialize_with = "serde_parse_ts_nanos"
+    )]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TsNanosStr {
+    #[serde(
+        serialize_with = "serde_t2ts_nanos_str",
+        deserialize_with = "serde_parse_ts_nanos_str"
+    )]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TsAndStr {
+    #[serde(
+        serialize_with = "serde_t2ts_and_str",
+        deserialize_with = "serde_parse_ts_and_str"
+    )]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TsPair {
+    #[serde(
+        serialize_with = "serde_t2ts_pair",
+        deserialize_with = "serde_parse_ts_pair"
+    )]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TsMillis {
+    #[serde(
+        serialize_with = "serde_t2ts_millis",
+        deserialize_with = "serde_parse_ts_millis"
+    )]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Rfc3339 {
+    #[serde(
+        serialize_with = "serde_t2rfc3339",
+        deserialize_with = "serde_parse_rfc3339"
+    )]
+    t: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TsOpt {
+    #[serde(
+        default,
+        serialize_with = "serde_t2ts_opt",
+        deserialize_with = "serde_parse_ts_opt"
+    )]
+    t: Option<OffsetDateTime>,
+}
+
+#[test]
+fn test_serde_ts_nanos() {
+    let dt = create_test_datetime();
+    let json = serde_json::to_string(&TsNanos { t: dt }).unwrap();
+    assert_eq!(json, r#"{"t":1710484245123456789}"#);
+    let parsed: TsNanos = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt);
+
+    // Unlike serde_t2ts, which drops subseconds and parses back in UTC
+    let json = serde_json::to_string(&Ts { t: dt }).unwrap();
+    assert_eq!(json, r#"{"t":1710484245}"#);
+    let parsed: Ts = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt.replace_nanosecond(0).unwrap());
+    assert_eq!(parsed.t.offset(), UtcOffset::UTC);
+}
+
+#[test]
+fn test_serde_ts_nanos_str() {
+    let dt = create_test_datetime();
+    let json = serde_json::to_string(&TsNanosStr { t: dt }).unwrap();
+    assert_eq!(json, r#"{"t":"1710484245123456789"}"#);
+    let parsed: TsNanosStr = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt);
+
+    assert!(serde_json::from_str::<TsNanosStr>(r#"{"t":"abc"}"#).is_err());
+}
+
+#[test]
+fn test_serde_ts_and_str() {
+    let dt = create_test_datetime();
+    let json = serde_json::to_string(&TsAndStr { t: dt }).unwrap();
+    assert_eq!(
+        json,
+        r#"{"t":{"ts":1710484245,"human":"2024-03-15 14:30:45+08:00"}}"#
+    );
+    let parsed: TsAndStr = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt.replace_nanosecond(0).unwrap());
+}
+
+#[test]
+fn test_serde_ts_pair() {
+    let dt = create_test_datetime();
+    let json = serde_json::to_string(&TsPair { t: dt }).unwrap();
+    assert_eq!(json, r#"{"t":[1710484245,123456789]}"#);
+    let parsed: TsPair = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt);
+
+    // Malformed arrays
+    assert!(serde_json::from_str::<TsPair>(r#"{"t":[1710484245]}"#).is_err());
+    assert!(serde_json::from_str::<TsPair>(r#"{"t":[1710484245,0,0]}"#).is_err());
+    assert!(serde_json::from_str::<TsPair>(r#"{"t":[1710484245,1000000000]}"#).is_err());
+    assert!(serde_json::from_str::<TsPair>(r#"{"t":[1710484245,-1]}"#).is_err());
+}
+
+#[test]
+fn test_serde_ts_millis() {
+    let dt = create_test_datetime();
+    let json = serde_json::to_string(&TsMillis { t: dt }).unwrap();
+    assert_eq!(json, r#"{"t":1710484245123}"#);
+    let parsed: TsMillis = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt.replace_millisecond(123).unwrap());
+
+    // Pre-1970
+    let parsed: TsMillis = serde_json::from_str(r#"{"t":-1500}"#).unwrap();
+    assert_eq!(parsed.t, datetime!(1969-12-31 23:59:58.5 UTC));
+}
+
+#[test]
+fn test_serde_rfc3339() {
+    let dt = create_test_datetime();
+    let json = serde_json::to_string(&Rfc3339 { t: dt }).unwrap();
+    assert_eq!(json, r#"{"t":"2024-03-15T14:30:45.123456789+08:00"}"#);
+
+    // The input's offset is preserved
+    let parsed: Rfc3339 = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, dt);
+    assert_eq!(parsed.t.offset(), dt.offset());
+}
+
+#[test]
+fn test_serde_ts_opt() {
+    let dt = create_test_datetime().replace_nanosecond(0).unwrap();
+    let json = serde_json::to_string(&TsOpt { t: Some(dt) }).unwrap();
+    assert_eq!(json, r#"{"t":1710484245}"#);
+    let parsed: TsOpt = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.t, Some(dt));
+
+    let json = serde_json::to_string(&TsOpt { t: None }).unwrap();
+    assert_eq!(json, r#"{"t":null}"#);
+
+    // Present-but-null and absent fields both give None
+    let parsed: TsOpt = serde_json::from_str(r#"{"t":null}"#).unwrap();
+    assert_eq!(parsed.t, None);
+    let parsed: TsOpt = serde_json::from_str("{}").unwrap();
+    assert_eq!(parsed.t, None);
+}
@@ -1,5 +1,5 @@
-use ext_time::ExtTime;
-use time::macros::time;
+use ext_time::{ExtTime, SubUnit};
+use time::{Time, macros::time};
 
 #[test]
 fn test_shorten() {
@@ -10,6 +10,17 @@ fn test_shorten() {
     assert_eq!(t.to_shorten(), "9:05");
 }
 
+#[test]
+fn test_to_fixed_width() {
+    assert_eq!(time!(9:05).to_fixed_width(), "09:05:00");
+    assert_eq!(time!(0:00).to_fixed_width(), "00:00:00");
+    assert_eq!(time!(23:59:59.999).to_fixed_width(), "23:59:59");
+
+    for t in [time!(0:00), time!(9:05:07), time!(12:34:56.789)] {
+        assert_eq!(t.to_fixed_width().len(), 8);
+    }
+}
+
 #[test]
 fn test_from_str() {
     let t = <time::Time as ExtTime>::from_str("9:30").unwrap();
@@ -20,6 +31,65 @@ fn test_from_str() {
     assert!(<time::Time as ExtTime>::from_str("invalid").is_err());
 }
 
+#[test]
+fn test_from_str_with_known_zone() {
+    use std::collections::HashMap;
+
+    let zones: HashMap<&str, i8> = [("CST", 8), ("EST", -5), ("UTC", 0)].into_iter().collect();
+    let parse = <time::Time as ExtTime>::from_str_with_known_zone;
+
+    assert_eq!(parse("14:30 CST", &zones).unwrap(), (time!(14:30), 8));
+    assert_eq!(parse("9:05 EST", &zones).unwrap(), (time!(9:05), -5));
+    assert_eq!(parse(" 0:00  UTC ", &zones).unwrap(), (time!(0:00), 0));
+
+    assert!(matches!(
+        parse("14:30 PST", &zones),
+        Err(ext_time::TimeError::UnknownZone(zone)) if zone == "PST"
+    ));
+    assert!(parse("14:30", &zones).is_err());
+    assert!(parse("25:30 CST", &zones).is_err());
+}
+
+#[test]
+fn test_from_str_subsec() {
+    let parse = <time::Time as ExtTime>::from_str_subsec;
+
+    assert_eq!(parse("14:30:45.250").unwrap(), time!(14:30:45.250));
+    assert_eq!(parse("14:30:45").unwrap(), time!(14:30:45));
+    assert_eq!(parse("9:05:00.5").unwrap(), time!(9:05:00.5));
+    assert_eq!(
+        parse("23:59:59.123456789").unwrap(),
+        time!(23:59:59.123456789)
+    );
+
+    assert!(parse("14:30").is_err());
+    assert!(parse("14:30:45.").is_err());
+    assert!(parse("14:30:45.1234567890").is_err());
+    assert!(parse("14:30:45.+12").is_err());
+    assert!(parse("24:00:00").is_err());
+    assert!(parse("14:30:60.000").is_err());
+}
+
+#[test]
+fn test_from_iso_time() {
+    assert_eq!(Time::from_iso_time("14:30:45").unwrap(), time!(14:30:45));
+    assert_eq!(
+        Time::from_iso_time("14:30:45.123").unwrap(),
+        time!(14:30:45.123)
+    );
+    assert_eq!(
+        Time::from_iso_time("00:00:00.000000001").unwrap(),
+        time!(0:00:00.000000001)
+    );
+
+    assert!(Time::from_iso_time("9:05:00").is_err());
+    assert!(Time::from_iso_time("14:30").is_err());
+    assert!(Time::from_iso_time("14:30:45.").is_err());
+    assert!(Time::from_iso_time("24:00:00").is_err());
+    assert!(Time::from_iso_time("14:30:45Z").is_err());
+    assert!(Time::from_iso_time(" 14:30:45").is_err());
+}
+
 #[test]
 fn test_sub_ext() {
     let t1 = time!(23:00);
@@ -28,6 +98,62 @@ fn test_sub_ext() {
     assert_eq!(t2.sub_ext(t1), time::Duration::hours(2));
 }
 
+#[test]
+fn test_sub_ext_full() {
+    let t1 = time!(23:00);
+    let t2 = time!(1:00);
+    assert_eq!(t1.sub_ext_full(t2), time::Duration::hours(22));
+    assert_eq!(t2.sub_ext_full(t1), time::Duration::hours(2));
+
+    assert_eq!(t1.sub_ext(t1), time::Duration::ZERO);
+    assert_eq!(t1.sub_ext_full(t1), time::Duration::hours(24));
+}
+
+#[test]
+fn test_lerp() {
+    assert_eq!(time!(10:00).lerp(time!(12:00), 0.5), time!(11:00));
+    assert_eq!(time!(10:00).lerp(time!(12:00), 0.25), time!(10:30));
+    assert_eq!(time!(10:00).lerp(time!(12:00), 0.0), time!(10:00));
+    assert_eq!(time!(10:00).lerp(time!(12:00), 1.0), time!(12:00));
+
+    // Across midnight
+    assert_eq!(time!(23:00).lerp(time!(1:00), 0.5), time!(0:00));
+    assert_eq!(time!(22:00).lerp(time!(1:00), 0.5), time!(23:30));
+
+    // Clamped
+    assert_eq!(time!(10:00).lerp(time!(12:00), -1.0), time!(10:00));
+    assert_eq!(time!(10:00).lerp(time!(12:00), 2.0), time!(12:00));
+}
+
+#[test]
+fn test_on_date() {
+    use time::macros::{date, datetime};
+
+    let dt = time!(14:30:45.250)
+        .on_date(date!(2024 - 03 - 15), 8)
+        .unwrap();
+    assert_eq!(dt, datetime!(2024-03-15 14:30:45.250 +08:00));
+    assert_eq!(dt.offset().whole_hours(), 8);
+
+    let dt = time!(0:00).on_date(date!(2024 - 02 - 29), -5).unwrap();
+    assert_eq!(dt, datetime!(2024-02-29 00:00 -05:00));
+
+    assert!(time!(0:00).on_date(date!(2024 - 02 - 29), 30).is_err());
+}
+
+#[test]
+fn test_is_whole_minute_and_hour() {
+    assert!(time!(14:30).is_whole_minute());
+    assert!(!time!(14:30).is_whole_hour());
+    assert!(time!(14:00).is_whole_minute());
+    assert!(time!(14:00).is_whole_hour());
+    assert!(time!(0:00).is_whole_hour());
+
+    assert!(!time!(14:30:01).is_whole_minute());
+    assert!(!time!(14:00:00.001).is_whole_minute());
+    assert!(!time!(14:00:00.001).is_whole_hour());
+}
+
 #[test]
 fn test_is_between() {
     let t = time!(23:30);
@@ -40,6 +166,67 @@ fn test_is_between() {
     assert!(!t.is_between(time!(23:00), time!(1:00)));
 }
 
+#[test]
+fn test_position_in() {
+    use ext_time::Position;
+
+    let (open, close) = (time!(9:30), time!(16:00));
+    assert_eq!(time!(9:00).position_in(open, close), Position::Before);
+    assert_eq!(time!(9:30).position_in(open, close), Position::Inside);
+    assert_eq!(time!(16:00).position_in(open, close), Position::Inside);
+    assert_eq!(time!(16:01).position_in(open, close), Position::After);
+
+    // Cross-day window
+    let (open, close) = (time!(21:00), time!(2:30));
+    assert_eq!(time!(23:00).position_in(open, close), Position::Inside);
+    assert_eq!(time!(1:00).position_in(open, close), Position::Inside);
+    assert_eq!(time!(12:00).position_in(open, close), Position::Before);
+    assert_eq!(time!(2:31).position_in(open, close), Position::Before);
+}
+
+#[test]
+fn test_is_in_any() {
+    let sessions = [
+        (time!(9:00), time!(11:30)),
+        (time!(13:30), time!(15:00)),
+        (time!(21:00), time!(2:30)),
+    ];
+    assert!(time!(10:00).is_in_any(&sessions));
+    assert!(time!(15:00).is_in_any(&sessions));
+    assert!(time!(23:30).is_in_any(&sessions));
+    assert!(time!(1:00).is_in_any(&sessions));
+    assert!(!time!(12:00).is_in_any(&sessions));
+    assert!(!time!(3:00).is_in_any(&sessions));
+
+    // Overlapping windows
+    let overlapping = [(time!(9:00), time!(12:00)), (time!(11:00), time!(14:00))];
+    assert!(time!(11:30).is_in_any(&overlapping));
+    assert!(time!(13:00).is_in_any(&overlapping));
+    assert!(!time!(14:01).is_in_any(&overlapping));
+
+    assert!(!time!(10:00).is_in_any(&[]));
+}
+
+#[test]
+fn test_classify() {
+    let sessions = [
+        ("pre-market", time!(4:00), time!(9:29:59)),
+        ("regular", time!(9:30), time!(16:00)),
+        ("after-hours", time!(16:00), time!(20:00)),
+        ("overnight", time!(20:00:01), time!(3:59:59)),
+    ];
+    assert_eq!(time!(5:00).classify(&sessions), Some("pre-market"));
+    assert_eq!(time!(9:30).classify(&sessions), Some("regular"));
+    // Boundary shared by two windows goes to the first
+    assert_eq!(time!(16:00).classify(&sessions), Some("regular"));
+    assert_eq!(time!(18:00).classify(&sessions), Some("after-hours"));
+    assert_eq!(time!(23:00).classify(&sessions), Some("overnight"));
+    assert_eq!(time!(2:00).classify(&sessions), Some("overnight"));
+
+    assert_eq!(time!(12:00).classify(&sessions[..1]), None);
+    assert_eq!(time!(12:00).classify(&[]), None);
+}
+
 #[test]
 fn test_add_minutes() {
     let t = time!(23:30);
@@ -65,6 +252,17 @@ fn test_from_seconds() {
     assert!(<time::Time as ExtTime>::from_seconds(24 * 3600).is_err());
 }
 
+#[test]
+fn test_from_seconds_wrapping() {
+    let wrap = <time::Time as ExtTime>::from_seconds_wrapping;
+    assert_eq!(wrap(37230), time!(10:20:30));
+    assert_eq!(wrap(-1), time!(23:59:59));
+    assert_eq!(wrap(24 * 3600), time!(0:00));
+    assert_eq!(wrap(24 * 3600 + 3660), time!(1:01));
+    assert_eq!(wrap(-3 * 24 * 3600 - 60), time!(23:59));
+    assert_eq!(wrap(i64::MIN), wrap(i64::MIN.rem_euclid(24 * 3600)));
+}
+
 #[test]
 fn test_to_seconds() {
     let t = time!(10:20:30);
@@ -80,6 +278,22 @@ fn test_to_seconds() {
     assert_eq!(t.to_seconds(), 86399);
 }
 
+#[test]
+fn test_minutes_and_seconds_of_day() {
+    let t = time!(10:20:30);
+    assert_eq!(t.minutes_of_day(), 620);
+    assert_eq!(t.total_seconds_of_day(), 37230);
+    assert_eq!(t.total_seconds_of_day() as i64, t.to_seconds());
+
+    let t = time!(0:00);
+    assert_eq!(t.minutes_of_day(), 0);
+    assert_eq!(t.total_seconds_of_day(), 0);
+
+    let t = time!(23:59:59);
+    assert_eq!(t.minutes_of_day(), 1439);
+    assert_eq!(t.total_seconds_of_day(), 86399);
+}
+
 #[test]
 fn test_align_to() {
     // Test alignment to 5 minutes
@@ -109,10 +323,52 @@ fn test_align_to() {
     // 24 * 3600 is a valid alignment interval (24 hours)
     let aligned = t.align_to(24 * 3600).unwrap();
     assert_eq!(aligned.hour(), 0);
+
+    // Same boundaries as align_to_floor; the sign of the interval is ignored
+    let x = time!(14:37:12.5);
+    assert_eq!(x.align_to(900).unwrap(), x.align_to_floor(900).unwrap());
+    assert_eq!(x.align_to(-900).unwrap(), x.align_to_floor(900).unwrap());
+    assert!(x.align_to_floor(-900).is_err());
     assert_eq!(aligned.minute(), 0);
     assert_eq!(aligned.second(), 0);
 }
 
+#[test]
+fn test_align_to_rounding_variants() {
+    let t = time!(14:32:30);
+    assert_eq!(t.align_to_floor(300).unwrap(), time!(14:30));
+    assert_eq!(t.align_to_ceil(300).unwrap(), time!(14:35));
+    assert_eq!(t.align_to_nearest(300).unwrap(), time!(14:35)); // half-up
+    assert_eq!(time!(14:32:29).align_to_nearest(300).unwrap(), time!(14:30));
+
+    // Already aligned times stay put
+    let t = time!(14:30);
+    assert_eq!(t.align_to_floor(300).unwrap(), t);
+    assert_eq!(t.align_to_ceil(300).unwrap(), t);
+    assert_eq!(t.align_to_nearest(300).unwrap(), t);
+
+    // align_to is the floor variant
+    let t = time!(10:34:59.9);
+    assert_eq!(t.align_to(300).unwrap(), t.align_to_floor(300).unwrap());
+
+    // Ceil wraps at midnight
+    assert_eq!(time!(23:58).align_to_ceil(300).unwrap(), time!(0:00));
+
+    assert!(t.align_to_floor(0).is_err());
+    assert!(t.align_to_ceil(-60).is_err());
+    assert!(t.align_to_nearest(0).is_err());
+}
+
+#[test]
+fn test_align_to_subsec() {
+    let t = time!(14:30:07.250);
+    assert_eq!(t.align_to_subsec(5, true).unwrap(), time!(14:30:05.250));
+    assert_eq!(t.align_to_subsec(5, false).unwrap(), time!(14:30:05));
+    assert_eq!(t.align_to_subsec(5, false).unwrap(), t.align_to(5).unwrap());
+
+    assert!(t.align_to_subsec(0, true).is_err());
+}
+
 #[test]
 fn test_next_hour() {
     let t = time!(10:30:45);
@@ -176,6 +432,36 @@ fn test_next_second() {
     assert_eq!(next.second(), 0);
 }
 
+#[test]
+fn test_prev_hour() {
+    let t = time!(10:30:45);
+    assert_eq!(t.prev_hour(), time!(09:30:45));
+    assert_eq!(time!(00:30:45).prev_hour(), time!(23:30:45));
+    assert_eq!(time!(23:30:45).next_hour().prev_hour(), time!(23:30:45));
+    assert_eq!(t.prev_day(), t);
+}
+
+#[test]
+fn test_prev_minute_and_second() {
+    assert_eq!(time!(10:30:45).prev_minute(), time!(10:29:45));
+    assert_eq!(time!(10:00:45).prev_minute(), time!(09:59:45));
+    assert_eq!(time!(00:00:45).prev_minute(), time!(23:59:45));
+
+    assert_eq!(time!(10:30:45).prev_second(), time!(10:30:44));
+    assert_eq!(time!(10:30:00).prev_second(), time!(10:29:59));
+    assert_eq!(time!(10:00:00).prev_second(), time!(09:59:59));
+    assert_eq!(time!(00:00:00).prev_second(), time!(23:59:59));
+}
+
+#[test]
+fn test_prev_keeps_subseconds() {
+    let t = time!(00:00:00.123456789);
+    assert_eq!(t.prev_hour(), time!(23:00:00.123456789));
+    assert_eq!(t.prev_minute(), time!(23:59:00.123456789));
+    assert_eq!(t.prev_second(), time!(23:59:59.123456789));
+    assert_eq!(t.prev_day(), t);
+}
+
 #[test]
 fn test_to_hour_seconds() {
     let t = time!(10:20:30);
@@ -198,4 +484,253 @@ fn test_to_minute_seconds() {
 
     let t = time!(23:59:59);
     assert_eq!(t.to_minute_seconds(), 86340); // 23 * 3600 + 59 * 60
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_intervals_until() {
+    let t = time!(9:30);
+    assert_eq!(t.intervals_until(time!(10:00), 300).unwrap(), 6);
+    assert_eq!(t.intervals_until(time!(10:02), 300).unwrap(), 6);
+    assert_eq!(t.intervals_until(time!(9:30), 300).unwrap(), 0);
+
+    // Cross-day
+    let t = time!(23:00);
+    assert_eq!(t.intervals_until(time!(1:00), 3600).unwrap(), 2);
+
+    assert!(t.intervals_until(time!(1:00), 0).is_err());
+    assert!(t.intervals_until(time!(1:00), -60).is_err());
+}
+
+#[test]
+fn test_split_hms() {
+    assert_eq!(time!(14:30:45).split_hms(), (14, 30, 45));
+    assert_eq!(time!(0:00).split_hms(), (0, 0, 0));
+    assert_eq!(time!(23:59:59.999).split_hms(), (23, 59, 59));
+}
+
+#[test]
+fn test_until_next_boundary() {
+    let t = time!(14:32);
+    assert_eq!(
+        t.until_next_boundary(300).unwrap(),
+        time::Duration::minutes(3)
+    );
+
+    // On a boundary, the following one is used
+    let t = time!(14:35);
+    assert_eq!(
+        t.until_next_boundary(300).unwrap(),
+        time::Duration::minutes(5)
+    );
+
+    // Subseconds are taken into account
+    let t = time!(14:34:59.5);
+    assert_eq!(
+        t.until_next_boundary(300).unwrap(),
+        time::Duration::milliseconds(500)
+    );
+
+    // Wraps to next day's 00:00
+    let t = time!(23:58);
+    assert_eq!(
+        t.until_next_boundary(300).unwrap(),
+        time::Duration::minutes(2)
+    );
+    let t = time!(22:00);
+    assert_eq!(
+        t.until_next_boundary(7 * 3600).unwrap(),
+        time::Duration::hours(2)
+    );
+
+    assert!(t.until_next_boundary(0).is_err());
+    assert!(t.until_next_boundary(-300).is_err());
+}
+
+#[test]
+fn test_add_and_sub_seconds() {
+    let t = time!(23:59:30.250);
+    assert_eq!(t.add_seconds(45), time!(0:00:15.250));
+    assert_eq!(t.add_seconds(-30), time!(23:59:00.250));
+    assert_eq!(t.add_seconds(86400), t);
+
+    let t = time!(0:00:15.250);
+    assert_eq!(t.sub_seconds(45), time!(23:59:30.250));
+    assert_eq!(t.sub_seconds(-45), time!(0:01:00.250));
+    assert_eq!(t.sub_seconds(3 * 86400), t);
+
+    // Extreme inputs wrap instead of overflowing
+    assert_eq!(
+        t.sub_seconds(i64::MIN),
+        t.add_seconds(i64::MAX).add_seconds(1)
+    );
+}
+
+#[test]
+fn test_surrounding_boundaries() {
+    let t = time!(14:32);
+    assert_eq!(
+        t.surrounding_boundaries(300).unwrap(),
+        (time!(14:30), time!(14:35))
+    );
+
+    // On a boundary
+    let t = time!(14:35);
+    assert_eq!(t.surrounding_boundaries(300).unwrap(), (t, t));
+
+    // Subseconds count as off-grid
+    let t = time!(14:35:00.001);
+    assert_eq!(
+        t.surrounding_boundaries(300).unwrap(),
+        (time!(14:35), time!(14:40))
+    );
+
+    // Ceil wraps to midnight
+    let t = time!(23:58);
+    assert_eq!(
+        t.surrounding_boundaries(300).unwrap(),
+        (time!(23:55), time!(0:00))
+    );
+    let t = time!(22:00);
+    assert_eq!(
+        t.surrounding_boundaries(7 * 3600).unwrap(),
+        (time!(21:00), time!(0:00))
+    );
+
+    assert!(t.surrounding_boundaries(0).is_err());
+    assert!(t.surrounding_boundaries(-300).is_err());
+}
+
+#[test]
+fn test_round_to() {
+    use ext_time::AlignMode;
+
+    let t = time!(14:32);
+    assert_eq!(t.round_to(300, AlignMode::Floor).unwrap(), time!(14:30));
+    assert_eq!(t.round_to(300, AlignMode::Ceil).unwrap(), time!(14:35));
+    assert_eq!(t.round_to(300, AlignMode::Round).unwrap(), time!(14:30));
+
+    let t = time!(14:33);
+    assert_eq!(t.round_to(300, AlignMode::Round).unwrap(), time!(14:35));
+    // Tie rounds up
+    let t = time!(14:32:30);
+    assert_eq!(t.round_to(300, AlignMode::Round).unwrap(), time!(14:35));
+
+    // Already aligned
+    let t = time!(14:35);
+    assert_eq!(t.round_to(300, AlignMode::Floor).unwrap(), t);
+    assert_eq!(t.round_to(300, AlignMode::Ceil).unwrap(), t);
+    assert_eq!(t.round_to(300, AlignMode::Round).unwrap(), t);
+
+    // Ceil and round wrap past midnight
+    let t = time!(23:58);
+    assert_eq!(t.round_to(300, AlignMode::Ceil).unwrap(), time!(0:00));
+    assert_eq!(t.round_to(300, AlignMode::Round).unwrap(), time!(0:00));
+    assert_eq!(t.round_to(300, AlignMode::Floor).unwrap(), time!(23:55));
+
+    assert!(t.round_to(0, AlignMode::Floor).is_err());
+    assert!(t.round_to(-300, AlignMode::Ceil).is_err());
+}
+
+#[test]
+fn test_truncate_to_unit() {
+    let t = time!(14:30:45.123_456_789);
+    assert_eq!(t.truncate_to_unit(SubUnit::Milli), time!(14:30:45.123));
+    assert_eq!(t.truncate_to_unit(SubUnit::Micro), time!(14:30:45.123_456));
+    assert_eq!(t.truncate_to_unit(SubUnit::Second), time!(14:30:45));
+
+    // Already coarse times are unchanged
+    assert_eq!(
+        time!(14:30:45).truncate_to_unit(SubUnit::Milli),
+        time!(14:30:45)
+    );
+}
+
+#[test]
+fn test_quarter_hour() {
+    assert_eq!(time!(14:07:30).next_quarter_hour(), time!(14:15));
+    assert_eq!(time!(14:15).next_quarter_hour(), time!(14:30));
+    assert_eq!(time!(14:59:59.999).next_quarter_hour(), time!(15:00));
+    assert_eq!(time!(23:50).next_quarter_hour(), time!(0:00));
+
+    assert_eq!(time!(14:07:30).align_quarter_hour(), time!(14:00));
+    assert_eq!(time!(14:15).align_quarter_hour(), time!(14:15));
+    assert_eq!(time!(23:59:59.999).align_quarter_hour(), time!(23:45));
+}
+
+#[test]
+fn test_from_duration_since_midnight() {
+    use time::Duration;
+
+    assert_eq!(
+        Time::from_duration_since_midnight(Duration::hours(25)),
+        (time!(1:00), 1)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight(Duration::hours(-1)),
+        (time!(23:00), -1)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight(Duration::hours(-24)),
+        (time!(0:00), -1)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight(Duration::milliseconds(52_245_500)),
+        (time!(14:30:45.5), 0)
+    );
+    assert_eq!(
+        Time::from_duration_since_midnight(Duration::days(3) + Duration::minutes(90)),
+        (time!(1:30), 3)
+    );
+}
+
+#[test]
+fn test_nth_tick() {
+    let t = time!(9:30);
+    assert_eq!(t.nth_tick(300, 0), t);
+    assert_eq!(t.nth_tick(300, 3), time!(9:45));
+    assert_eq!(t.nth_tick(300, -6), time!(9:00));
+    assert_eq!(t.nth_tick(3600, 15), time!(0:30)); // wraps past midnight
+    assert_eq!(t.nth_tick(60, 1440), t);
+
+    // Large products don't overflow
+    assert_eq!(t.nth_tick(86400, i64::MAX), t);
+}
+
+#[test]
+fn test_hand_angles() {
+    let t = time!(1:30);
+    assert_eq!(t.hour_angle(), 45.0);
+    assert_eq!(t.minute_angle(), 180.0);
+    assert_eq!(t.second_angle(), 0.0);
+
+    let t = time!(0:00);
+    assert_eq!(t.hour_angle(), 0.0);
+    assert_eq!(time!(12:00).hour_angle(), 0.0);
+    assert_eq!(time!(15:00).hour_angle(), 90.0);
+
+    // Sub-unit progress moves the hands continuously
+    let t = time!(9:15:30.5);
+    assert_eq!(t.second_angle(), 183.0);
+    assert!((t.minute_angle() - 93.05).abs() < 1e-9);
+    assert!((t.hour_angle() - (270.0 + 93.05 / 12.0)).abs() < 1e-9);
+
+    let t = time!(23:59:59.999);
+    assert!(t.hour_angle() < 360.0);
+    assert!(t.minute_angle() < 360.0);
+    assert!(t.second_angle() < 360.0);
+}
+
+#[test]
+fn test_prev_aligned() {
+    assert_eq!(time!(14:32:30).prev_aligned(300).unwrap(), time!(14:30));
+    // On a boundary steps back a full interval
+    assert_eq!(time!(14:30).prev_aligned(300).unwrap(), time!(14:25));
+    assert_eq!(time!(14:30:00.001).prev_aligned(300).unwrap(), time!(14:30));
+    // Midnight wraps to the previous day's last boundary
+    assert_eq!(time!(0:00).prev_aligned(300).unwrap(), time!(23:55));
+    assert_eq!(time!(0:00).prev_aligned(7 * 3600).unwrap(), time!(21:00));
+    assert_eq!(time!(0:00).prev_aligned(86400).unwrap(), time!(0:00));
+
+    assert!(time!(14:30).prev_aligned(0).is_err());
+    assert!(time!(14:30).prev_aligned(-300).is_err());
+}
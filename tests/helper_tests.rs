@@ -0,0 +1,174 @@
+use ext_time::{
+    max_datetime, mean_time, min_datetime, offset_has_minutes, offset_hours_to_seconds,
+    offset_seconds_to_utcoffset, offset_to_string, offset_to_whole_hours, parse_duration,
+    parse_holidays, weekday_from_iso, weekday_from_sunday0, weekday_from_u8, weekday_to_iso,
+    weekday_to_sunday0, weekday_to_u8,
+};
+use time::{
+    Date, Duration, Month, UtcOffset, Weekday,
+    macros::{datetime, time},
+};
+
+#[test]
+fn test_offset_hours_to_seconds() {
+    assert_eq!(offset_hours_to_seconds(8), 28800);
+    assert_eq!(offset_hours_to_seconds(0), 0);
+    assert_eq!(offset_hours_to_seconds(-5), -18000);
+}
+
+#[test]
+fn test_offset_seconds_to_utcoffset() {
+    let offset = offset_seconds_to_utcoffset(5 * 3600 + 30 * 60).unwrap();
+    assert_eq!(offset.whole_hours(), 5);
+    assert_eq!(offset.minutes_past_hour(), 30);
+
+    let offset = offset_seconds_to_utcoffset(offset_hours_to_seconds(-8)).unwrap();
+    assert_eq!(offset.whole_hours(), -8);
+
+    assert!(offset_seconds_to_utcoffset(26 * 3600).is_err());
+}
+
+#[test]
+fn test_offset_to_string() {
+    assert_eq!(
+        offset_to_string(UtcOffset::from_hms(8, 0, 0).unwrap()),
+        "+08:00"
+    );
+    assert_eq!(
+        offset_to_string(UtcOffset::from_hms(-5, -30, 0).unwrap()),
+        "-05:30"
+    );
+    assert_eq!(offset_to_string(UtcOffset::UTC), "+00:00");
+    assert_eq!(
+        offset_to_string(UtcOffset::from_hms(0, -30, 0).unwrap()),
+        "-00:30"
+    );
+}
+
+#[test]
+fn test_parse_holidays() {
+    let holidays = parse_holidays(&["20240101", "20241225", "20240101"]).unwrap();
+    assert_eq!(holidays.len(), 2);
+    assert!(holidays.contains(&Date::from_calendar_date(2024, Month::January, 1).unwrap()));
+    assert!(holidays.contains(&Date::from_calendar_date(2024, Month::December, 25).unwrap()));
+
+    assert!(parse_holidays(&[]).unwrap().is_empty());
+    assert!(parse_holidays(&["20240101", "2024-12-25"]).is_err());
+    assert!(parse_holidays(&["20240230"]).is_err());
+}
+
+#[test]
+fn test_parse_duration() {
+    assert_eq!(parse_duration("1h30m").unwrap(), Duration::minutes(90));
+    assert_eq!(parse_duration("90m").unwrap(), Duration::minutes(90));
+    assert_eq!(parse_duration("45s").unwrap(), Duration::seconds(45));
+    assert_eq!(parse_duration("2d").unwrap(), Duration::days(2));
+    assert_eq!(
+        parse_duration("1d2h3m4s").unwrap(),
+        Duration::days(1) + Duration::hours(2) + Duration::minutes(3) + Duration::seconds(4)
+    );
+    assert_eq!(parse_duration("0s").unwrap(), Duration::ZERO);
+
+    assert!(parse_duration("").is_err());
+    assert!(parse_duration("90").is_err());
+    assert!(parse_duration("1h30").is_err());
+    assert!(parse_duration("h").is_err());
+    assert!(parse_duration("1w").is_err());
+    assert!(parse_duration("-5m").is_err());
+    assert!(parse_duration("99999999999999999999d").is_err());
+}
+
+#[test]
+fn test_min_max_datetime() {
+    let dts = [
+        datetime!(2024-03-15 10:00 +08:00), // 02:00 UTC
+        datetime!(2024-03-15 03:00 UTC),
+        datetime!(2024-03-14 20:00 -05:00), // 01:00 UTC
+    ];
+    assert_eq!(min_datetime(&dts), Some(dts[2]));
+    assert_eq!(max_datetime(&dts), Some(dts[1]));
+
+    assert_eq!(min_datetime(&[]), None);
+    assert_eq!(max_datetime(&[]), None);
+}
+
+#[test]
+fn test_mean_time() {
+    assert_eq!(mean_time(&[time!(23:00), time!(1:00)]), Some(time!(0:00)));
+    assert_eq!(mean_time(&[time!(10:00), time!(12:00)]), Some(time!(11:00)));
+    assert_eq!(
+        mean_time(&[time!(22:00), time!(0:00), time!(2:00)]),
+        Some(time!(0:00))
+    );
+    assert_eq!(
+        mean_time(&[time!(23:30), time!(0:30), time!(0:00)]),
+        Some(time!(0:00))
+    );
+    assert_eq!(mean_time(&[time!(14:30:45.5)]), Some(time!(14:30:45.5)));
+
+    assert_eq!(mean_time(&[]), None);
+    assert_eq!(mean_time(&[time!(6:00), time!(18:00)]), None);
+}
+
+#[test]
+fn test_offset_to_whole_hours() {
+    let india = UtcOffset::from_hms(5, 30, 0).unwrap();
+    let nepal = UtcOffset::from_hms(5, 45, 0).unwrap();
+    let newfoundland = UtcOffset::from_hms(-3, -30, 0).unwrap();
+    let beijing = UtcOffset::from_hms(8, 0, 0).unwrap();
+    let new_york = UtcOffset::from_hms(-5, 0, 0).unwrap();
+
+    assert!(offset_has_minutes(india));
+    assert!(offset_has_minutes(nepal));
+    assert!(offset_has_minutes(newfoundland));
+    assert!(!offset_has_minutes(beijing));
+    assert!(!offset_has_minutes(UtcOffset::UTC));
+
+    assert_eq!(offset_to_whole_hours(beijing).unwrap(), 8);
+    assert_eq!(offset_to_whole_hours(new_york).unwrap(), -5);
+    assert_eq!(offset_to_whole_hours(UtcOffset::UTC).unwrap(), 0);
+    assert!(offset_to_whole_hours(india).is_err());
+    assert!(offset_to_whole_hours(newfoundland).is_err());
+}
+
+#[test]
+fn test_weekday_from_u8() {
+    assert_eq!(weekday_from_u8(0), Some(Weekday::Monday));
+    assert_eq!(weekday_from_u8(6), Some(Weekday::Sunday));
+    assert_eq!(weekday_from_u8(7), None);
+    assert_eq!(weekday_from_u8(u8::MAX), None);
+
+    let mut weekday = Weekday::Monday;
+    for _ in 0..7 {
+        assert_eq!(weekday_from_u8(weekday_to_u8(weekday)), Some(weekday));
+        weekday = weekday.next();
+    }
+}
+
+#[test]
+fn test_weekday_conventions() {
+    assert_eq!(weekday_to_iso(Weekday::Monday), 1);
+    assert_eq!(weekday_to_iso(Weekday::Sunday), 7);
+    assert_eq!(weekday_to_sunday0(Weekday::Sunday), 0);
+    assert_eq!(weekday_to_sunday0(Weekday::Monday), 1);
+    assert_eq!(weekday_to_sunday0(Weekday::Saturday), 6);
+
+    assert_eq!(weekday_from_iso(0), None);
+    assert_eq!(weekday_from_iso(8), None);
+    assert_eq!(weekday_from_sunday0(7), None);
+
+    let mut weekday = Weekday::Monday;
+    for _ in 0..7 {
+        assert_eq!(weekday_to_iso(weekday), weekday.number_from_monday());
+        assert_eq!(
+            weekday_to_sunday0(weekday),
+            weekday.number_days_from_sunday()
+        );
+        assert_eq!(weekday_from_iso(weekday_to_iso(weekday)), Some(weekday));
+        assert_eq!(
+            weekday_from_sunday0(weekday_to_sunday0(weekday)),
+            Some(weekday)
+        );
+        weekday = weekday.next();
+    }
+}
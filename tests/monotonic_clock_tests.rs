@@ -0,0 +1,30 @@
+use ext_time::MonotonicClock;
+
+#[test]
+fn test_monotonic_clock_strictly_increasing() {
+    let mut clock = MonotonicClock::new();
+    let mut last = clock.next(8).unwrap();
+    for _ in 0..10_000 {
+        let next = clock.next(8).unwrap();
+        assert!(next > last);
+        assert_eq!(next.offset().whole_hours(), 8);
+        last = next;
+    }
+}
+
+#[test]
+fn test_monotonic_clock_offset_change() {
+    let mut clock = MonotonicClock::new();
+    let a = clock.next(8).unwrap();
+    let b = clock.next(-5).unwrap();
+    assert!(b > a);
+    assert_eq!(b.offset().whole_hours(), -5);
+}
+
+#[test]
+fn test_monotonic_clock_invalid_offset() {
+    let mut clock = MonotonicClock::new();
+    let a = clock.next(8).unwrap();
+    assert!(clock.next(30).is_err());
+    assert!(clock.next(8).unwrap() > a);
+}
@@ -0,0 +1,27 @@
+use ext_time::{ExtTime, TimeRange};
+use time::macros::time;
+
+#[test]
+fn test_contains() {
+    let range = TimeRange::new(time!(9:30), time!(16:00));
+    assert!(range.contains(time!(9:30)));
+    assert!(range.contains(time!(12:00)));
+    assert!(range.contains(time!(16:00)));
+    assert!(!range.contains(time!(16:00:01)));
+
+    let overnight = TimeRange::new(time!(21:00), time!(2:30));
+    assert!(overnight.contains(time!(23:00)));
+    assert!(overnight.contains(time!(1:00)));
+    assert!(!overnight.contains(time!(12:00)));
+}
+
+#[test]
+fn test_is_in() {
+    let overnight = TimeRange::new(time!(21:00), time!(2:30));
+    assert!(time!(0:00).is_in(&overnight));
+    assert!(!time!(3:00).is_in(&overnight));
+    assert_eq!(
+        time!(22:15).is_in(&overnight),
+        time!(22:15).is_between(overnight.start, overnight.end)
+    );
+}
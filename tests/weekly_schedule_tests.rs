@@ -0,0 +1,49 @@
+use ext_time::WeeklySchedule;
+use time::{
+    Weekday,
+    macros::{datetime, time},
+};
+
+#[test]
+fn test_next_fire() {
+    let schedule = WeeklySchedule::new(vec![Weekday::Monday, Weekday::Wednesday], time!(9:30));
+
+    // Friday 2024-03-15 -> Monday 2024-03-18
+    let from = datetime!(2024-03-15 14:30:45 +08:00);
+    assert_eq!(
+        schedule.next_fire(from),
+        Some(datetime!(2024-03-18 09:30 +08:00))
+    );
+
+    // Exactly at a fire time counts
+    let from = datetime!(2024-03-18 09:30 +08:00);
+    assert_eq!(schedule.next_fire(from), Some(from));
+
+    // Later the same day moves on to the next selected weekday
+    let from = datetime!(2024-03-18 09:30:01 +08:00);
+    assert_eq!(
+        schedule.next_fire(from),
+        Some(datetime!(2024-03-20 09:30 +08:00))
+    );
+}
+
+#[test]
+fn test_next_fire_single_day() {
+    let schedule = WeeklySchedule::new(vec![Weekday::Friday], time!(9:00));
+
+    // Today's time has passed, so the next fire is a week later
+    let from = datetime!(2024-03-15 14:30:45 +08:00);
+    assert_eq!(
+        schedule.next_fire(from),
+        Some(datetime!(2024-03-22 09:00 +08:00))
+    );
+    assert_eq!(schedule.next_fire(from).unwrap().offset(), from.offset());
+
+    let empty = WeeklySchedule::new(vec![], time!(9:00));
+    assert_eq!(empty.next_fire(from), None);
+
+    // No fire time left before the end of the supported range
+    let last = datetime!(9999-12-31 23:00 UTC);
+    let schedule = WeeklySchedule::new(vec![last.weekday().previous()], time!(9:00));
+    assert_eq!(schedule.next_fire(last), None);
+}